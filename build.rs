@@ -13,12 +13,21 @@ fn main() {
   // we actually replace the C source, we don't need to re-run the build script.
   // This won't affect our ability to develop the Rust level bindings.
   println!("cargo:rerun-if-changed=build.rs");
+  for (_, cfg, _) in SDL_VERSIONS {
+    println!("cargo:rustc-check-cfg=cfg({})", cfg);
+  }
+  println!("cargo:rustc-check-cfg=cfg(docs_rs)");
 
   if cfg!(feature = "cargo_check") {
     println!("Run with `cargo_check` enabled, skipping the build.");
     return;
   }
 
+  if cfg!(feature = "dynamic_loading") {
+    println!("Run with `dynamic_loading` enabled, SDL2 is loaded at runtime.");
+    return;
+  }
+
   #[cfg(feature = "static_bundled_build")]
   {
     do_static_bundled();
@@ -49,15 +58,51 @@ fn do_static_bundled() {
   let target_vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
   println!("target_vendor:{}", target_vendor);
 
-  let mut cm = cmake::Config::new(
-    std::path::Path::new(&cargo_manifest_dir).join("SDL2-2.26.5"),
-  );
-  cm.profile("Release");
-  cm.static_crt(true);
-  cm.target(&target);
-  cm.define("SDL_SHARED", "ON");
-  cm.define("SDL_STATIC", "ON");
-  cm.define("HIDAPI", "ON");
+  // The C source to build, which is normally the copy bundled in the crate.
+  println!("cargo:rerun-if-env-changed=SDL2_SOURCE_DIR");
+  let source_dir = match env::var_os("SDL2_SOURCE_DIR") {
+    Some(dir) if !dir.is_empty() => {
+      // unlike the bundled source, this could change between builds.
+      let dir = std::path::PathBuf::from(dir);
+      println!("cargo:rerun-if-changed={}", dir.display());
+      dir
+    }
+    _ => std::path::Path::new(&cargo_manifest_dir).join("SDL2-2.26.5"),
+  };
+  println!("source_dir:{}", source_dir.display());
+  let sdl_version = read_source_version(&source_dir);
+  println!("sdl_version:{}", sdl_version);
+  // `static_bundled_build` enables `sdl_2_26`, so an older fork won't link.
+  if parse_version(&sdl_version).map_or(false, |v| v < (2, 26, 0)) {
+    panic!(
+      "The SDL2 source in `{}` is version {}, but `static_bundled_build` needs at least 2.26.0.",
+      source_dir.display(),
+      sdl_version
+    );
+  }
+
+  if cfg!(feature = "bundled_static_only") && cfg!(feature = "dynamic_link") {
+    panic!("`bundled_static_only` can't be used with `dynamic_link`.");
+  }
+
+  let mut options: Vec<(&str, &str)> = Vec::new();
+  if cfg!(feature = "bundled_static_only") {
+    options.push(("SDL_SHARED", "OFF"));
+  } else {
+    options.push(("SDL_SHARED", "ON"));
+  }
+  if cfg!(feature = "dynamic_link") {
+    options.push(("SDL_STATIC", "OFF"));
+  } else {
+    options.push(("SDL_STATIC", "ON"));
+  }
+  options.push(("HIDAPI", "ON"));
+  if cfg!(feature = "test_harness") {
+    options.push(("SDL_TEST", "ON"));
+  } else {
+    options.push(("SDL_TEST", "OFF"));
+  }
+  options.extend(bundled_cmake_options());
   // We need to set extra CMake options when building for Apple platforms.
   if target_vendor == "apple" {
     // CMake can handle the x86_64/aarch64 duality of Apple platforms, but
@@ -66,10 +111,10 @@ fn do_static_bundled() {
     // requested. See: https://github.com/rust-lang/cargo/issues/8875
     match target_arch.as_str() {
       "aarch64" => {
-        cm.define("CMAKE_OSX_ARCHITECTURES", "arm64");
+        options.push(("CMAKE_OSX_ARCHITECTURES", "arm64"));
       }
       "x86_64" => {
-        cm.define("CMAKE_OSX_ARCHITECTURES", "x86_64");
+        options.push(("CMAKE_OSX_ARCHITECTURES", "x86_64"));
       }
       arch => {
         println!("Unrecognized architecture for Apple platform \"{}\", not setting CMAKE_OSX_ARCHITECTURES", arch);
@@ -77,7 +122,25 @@ fn do_static_bundled() {
     }
   }
 
-  let build_output_path = cm.build();
+  let mut cm = cmake::Config::new(&source_dir);
+  cm.profile("Release");
+  cm.static_crt(true);
+  cm.target(&target);
+  for &(option, value) in &options {
+    cm.define(option, value);
+  }
+
+  println!("cargo:rerun-if-env-changed=FERMIUM_SDL_CACHE_DIR");
+  let build_output_path = match env::var_os("FERMIUM_SDL_CACHE_DIR") {
+    Some(cache_dir) if !cache_dir.is_empty() => build_cached(
+      &mut cm,
+      std::path::Path::new(&cache_dir),
+      &source_dir,
+      &target,
+      &options,
+    ),
+    _ => cm.build(),
+  };
   println!("build_output_path: {}", build_output_path.display());
 
   println!(
@@ -85,19 +148,25 @@ fn do_static_bundled() {
     build_output_path.join("lib").display()
   );
 
+  // `SDL2_test` is always a static lib that depends on SDL2, so it has to go
+  // before SDL2 on the link line.
+  if cfg!(feature = "test_harness") {
+    println!("cargo:rustc-link-lib=static=SDL2_test");
+  }
+  link_extension_libs(!cfg!(feature = "dynamic_link"));
+
+  if cfg!(feature = "dynamic_link") {
+    copy_bundled_shared_lib(&build_output_path, &out_dir, &target_os);
+  }
+
   if target.contains("windows") {
-    println!("cargo:rustc-link-lib=static=SDL2-static");
-    println!("cargo:rustc-link-lib=user32");
-    println!("cargo:rustc-link-lib=gdi32");
-    println!("cargo:rustc-link-lib=winmm");
-    println!("cargo:rustc-link-lib=imm32");
-    println!("cargo:rustc-link-lib=ole32");
-    println!("cargo:rustc-link-lib=oleaut32");
-    println!("cargo:rustc-link-lib=version");
-    println!("cargo:rustc-link-lib=uuid");
-    println!("cargo:rustc-link-lib=advapi32");
-    println!("cargo:rustc-link-lib=setupapi");
-    println!("cargo:rustc-link-lib=shell32");
+    if cfg!(feature = "dynamic_link") {
+      // this is the import lib for `SDL2.dll`
+      println!("cargo:rustc-link-lib=SDL2");
+    } else {
+      println!("cargo:rustc-link-lib=static=SDL2-static");
+      link_windows_system_libs();
+    }
   } else {
     let sdl2_cfg_cmd = format!(
       "{}",
@@ -120,13 +189,304 @@ fn do_static_bundled() {
     assert!(sd2_config_linking_stdout.len() > 0);
 
     process_sdl2_config_output(sd2_config_linking_stdout);
-    println!("cargo:rustc-link-lib=static=SDL2");
+    if cfg!(feature = "dynamic_link") {
+      println!("cargo:rustc-link-lib=SDL2");
+    } else {
+      println!("cargo:rustc-link-lib=static=SDL2");
+    }
+  }
+
+  // These are the bundled headers, as installed by the build (so they include
+  // the `SDL_config.h` generated for this build).
+  export_metadata(
+    Some(&build_output_path.join("include").join("SDL2")),
+    Some(&build_output_path.join("lib")),
+    Some(&sdl_version),
+    !cfg!(feature = "dynamic_link"),
+  );
+}
+
+/// Reads the SDL2 version out of the source's `SDL_version.h`.
+#[cfg(feature = "static_bundled_build")]
+fn read_source_version(source_dir: &std::path::Path) -> String {
+  let header_path = source_dir.join("include").join("SDL_version.h");
+  let header = std::fs::read_to_string(&header_path).unwrap_or_else(|e| {
+    panic!(
+      "Couldn't read `{}`, is that an SDL2 source folder? {}",
+      header_path.display(),
+      e
+    )
+  });
+  let define = |name: &str| -> &str {
+    header
+      .lines()
+      .find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+          (Some("#define"), Some(n), Some(value)) if n == name => Some(value),
+          _ => None,
+        }
+      })
+      .unwrap_or_else(|| {
+        panic!("`{}` doesn't define `{}`.", header_path.display(), name)
+      })
+  };
+  format!(
+    "{}.{}.{}",
+    define("SDL_MAJOR_VERSION"),
+    define("SDL_MINOR_VERSION"),
+    define("SDL_PATCHLEVEL")
+  )
+}
+
+/// This file is written into a cache entry once it's completely built.
+#[cfg(feature = "static_bundled_build")]
+const CACHE_COMPLETE_MARKER: &str = "fermium-cache-complete";
+
+/// Builds SDL2 within `FERMIUM_SDL_CACHE_DIR`, or reuses a build that's
+/// already there.
+///
+/// Each entry is keyed by a hash of the source files, the target, and the CMake
+/// options, so changing any of those gives a fresh build. The build happens in
+/// a temporary folder that's renamed into place when it's done, so a build
+/// running at the same time never sees a half finished entry.
+#[cfg(feature = "static_bundled_build")]
+fn build_cached(
+  cm: &mut cmake::Config, cache_dir: &std::path::Path,
+  source_dir: &std::path::Path, target: &str, options: &[(&str, &str)],
+) -> std::path::PathBuf {
+  let mut hasher = Fnv1a::new();
+  hash_source_tree(&mut hasher, source_dir, source_dir);
+  hasher.write(target.as_bytes());
+  hasher.write(&[0]);
+  for (option, value) in options {
+    hasher.write(option.as_bytes());
+    hasher.write(&[0]);
+    hasher.write(value.as_bytes());
+    hasher.write(&[0]);
+  }
+  let key = format!("sdl2-{:016x}", hasher.0);
+  let cached = cache_dir.join(&key);
+  println!("cache_entry:{}", cached.display());
+  if cached.join(CACHE_COMPLETE_MARKER).is_file() {
+    println!("Using the cached SDL2 build.");
+    return cached;
+  }
+
+  let staging = cache_dir.join(format!("{}.tmp-{}", key, std::process::id()));
+  let _ = std::fs::remove_dir_all(&staging);
+  std::fs::create_dir_all(&staging).unwrap_or_else(|e| {
+    panic!("Couldn't create `{}`: {}", staging.display(), e)
+  });
+  cm.out_dir(&staging);
+  let built = cm.build();
+  // Only the installed files are used, not the CMake build folder.
+  let _ = std::fs::remove_dir_all(built.join("build"));
+  std::fs::write(built.join(CACHE_COMPLETE_MARKER), &key).unwrap();
+
+  if cached.is_dir() && !cached.join(CACHE_COMPLETE_MARKER).is_file() {
+    // left over from a build that was interrupted somehow.
+    let _ = std::fs::remove_dir_all(&cached);
+  }
+  match std::fs::rename(&staging, &cached) {
+    Ok(()) => cached,
+    Err(_) if cached.join(CACHE_COMPLETE_MARKER).is_file() => {
+      // Another build finished the same entry first, so use that one.
+      let _ = std::fs::remove_dir_all(&staging);
+      cached
+    }
+    Err(e) => panic!(
+      "Couldn't move `{}` to `{}`: {}",
+      staging.display(),
+      cached.display(),
+      e
+    ),
+  }
+}
+
+/// Hashes the relative path and content of every file in a folder, in a
+/// consistent order. Any `.git` folders are skipped.
+#[cfg(feature = "static_bundled_build")]
+fn hash_source_tree(
+  hasher: &mut Fnv1a, root: &std::path::Path, dir: &std::path::Path,
+) {
+  let mut entries: Vec<std::path::PathBuf> = std::fs::read_dir(dir)
+    .unwrap_or_else(|e| panic!("Couldn't read `{}`: {}", dir.display(), e))
+    .map(|entry| entry.unwrap().path())
+    .collect();
+  entries.sort();
+  for path in entries {
+    if path.file_name() == Some(std::ffi::OsStr::new(".git")) {
+      continue;
+    }
+    if path.is_dir() {
+      hash_source_tree(hasher, root, &path);
+    } else {
+      let relative = path.strip_prefix(root).unwrap();
+      // use `/` on all platforms so the key doesn't depend on the host.
+      for component in relative.components() {
+        hasher.write(component.as_os_str().to_string_lossy().as_bytes());
+        hasher.write(b"/");
+      }
+      hasher.write(&[0]);
+      hasher.write(&std::fs::read(&path).unwrap());
+    }
+  }
+}
+
+/// 64-bit FNV-1a, which (unlike `DefaultHasher`) always gives the same hash
+/// between builds and Rust versions.
+#[cfg(feature = "static_bundled_build")]
+struct Fnv1a(u64);
+#[cfg(feature = "static_bundled_build")]
+impl Fnv1a {
+  fn new() -> Self {
+    Self(0xcbf2_9ce4_8422_2325)
+  }
+  fn write(&mut self, bytes: &[u8]) {
+    for &b in bytes {
+      self.0 ^= u64::from(b);
+      self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+    }
+  }
+}
+
+/// The CMake options for the `bundled_*` and `headless` features.
+#[cfg(feature = "static_bundled_build")]
+fn bundled_cmake_options() -> Vec<(&'static str, &'static str)> {
+  let mut options = Vec::new();
+  let on_off = |b: bool| if b { "ON" } else { "OFF" };
+
+  for (feature_on, option) in [
+    (cfg!(feature = "bundled_no_audio"), "SDL_AUDIO"),
+    (cfg!(feature = "bundled_no_haptic"), "SDL_HAPTIC"),
+    (cfg!(feature = "bundled_no_joystick"), "SDL_JOYSTICK"),
+    (cfg!(feature = "bundled_no_sensor"), "SDL_SENSOR"),
+    (cfg!(feature = "bundled_no_render"), "SDL_RENDER"),
+    (cfg!(feature = "bundled_no_hidapi"), "SDL_HIDAPI"),
+  ] {
+    if feature_on {
+      println!("disabling:{}", option);
+      options.push((option, "OFF"));
+    }
+  }
+
+  // If any video backend is picked then only the picked ones (and the dummy
+  // driver) are built, otherwise CMake decides based on the platform.
+  let x11 = cfg!(feature = "bundled_video_x11");
+  let wayland = cfg!(feature = "bundled_video_wayland");
+  let kmsdrm = cfg!(feature = "bundled_video_kmsdrm");
+  let offscreen = cfg!(feature = "bundled_video_offscreen");
+  if x11 || wayland || kmsdrm || offscreen {
+    println!(
+      "video_backends: x11={} wayland={} kmsdrm={} offscreen={}",
+      x11, wayland, kmsdrm, offscreen
+    );
+    options.push(("SDL_X11", on_off(x11)));
+    options.push(("SDL_WAYLAND", on_off(wayland)));
+    options.push(("SDL_KMSDRM", on_off(kmsdrm)));
+    options.push(("SDL_OFFSCREEN", on_off(offscreen)));
+    options.push(("SDL_DUMMYVIDEO", "ON"));
+    options.push(("SDL_RPI", "OFF"));
+    options.push(("SDL_VIVANTE", "OFF"));
+    options.push(("SDL_DIRECTFB", "OFF"));
+  }
+
+  if cfg!(feature = "headless") {
+    // Only the dummy audio driver, and nothing that needs a system package.
+    for option in [
+      "SDL_ALSA",
+      "SDL_JACK",
+      "SDL_ESD",
+      "SDL_PIPEWIRE",
+      "SDL_PULSEAUDIO",
+      "SDL_ARTS",
+      "SDL_NAS",
+      "SDL_SNDIO",
+      "SDL_OSS",
+      "SDL_FUSIONSOUND",
+      "SDL_DISKAUDIO",
+      "SDL_LIBSAMPLERATE",
+      "SDL_DBUS",
+      "SDL_IBUS",
+    ] {
+      options.push((option, "OFF"));
+    }
+    options.push(("SDL_DUMMYAUDIO", "ON"));
+  }
+  options
+}
+
+/// Copies the shared SDL2 from a `dynamic_link` build next to the executables,
+/// and gives this crate's own executables a matching rpath.
+///
+/// Build scripts can only set link args for their own package, so other
+/// packages need to set `-Wl,-rpath,$ORIGIN` themselves (or `@loader_path` on
+/// macOS) if they want to find the library this way.
+#[cfg(feature = "static_bundled_build")]
+fn copy_bundled_shared_lib(
+  build_output_path: &std::path::Path, out_dir: &str, target_os: &str,
+) {
+  // The file to copy is the one named by the library's soname / install name,
+  // since that's the name the dynamic loader will go looking for.
+  let (lib_from, file_name) = match target_os {
+    "windows" => (build_output_path.join("bin").join("SDL2.dll"), "SDL2.dll"),
+    "macos" | "ios" => {
+      let name = "libSDL2-2.0.0.dylib";
+      (build_output_path.join("lib").join(name), name)
+    }
+    _ => {
+      let name = "libSDL2-2.0.so.0";
+      (build_output_path.join("lib").join(name), name)
+    }
+  };
+  println!("lib_from:{}", lib_from.display());
+
+  // `OUT_DIR` is `target/<profile>/build/fermium-<hash>/out`, and executables
+  // go in `target/<profile>`, or in `deps` or `examples` within that.
+  let profile_dir = std::path::Path::new(out_dir)
+    .ancestors()
+    .nth(3)
+    .expect("Couldn't find the target profile folder from `OUT_DIR`.");
+  for dir in [
+    profile_dir.to_path_buf(),
+    profile_dir.join("deps"),
+    profile_dir.join("examples"),
+  ] {
+    if dir.is_dir() {
+      let lib_to = dir.join(file_name);
+      println!("lib_to:{}", lib_to.display());
+      // `copy` follows symlinks, so this gets the real library file.
+      std::fs::copy(&lib_from, lib_to).unwrap();
+    }
+  }
+
+  match target_os {
+    "windows" => (),
+    "macos" | "ios" => {
+      println!("cargo:rustc-link-arg=-Wl,-rpath,@loader_path");
+    }
+    _ => println!("cargo:rustc-link-arg=-Wl,-rpath,$ORIGIN"),
   }
 }
 
 #[cfg(not(feature = "static_bundled_build"))]
 fn do_dynamic_system() {
-  println!("Performing dynamically-linked build against the system SDL2.");
+  // Note: Despite the name, this can also static link (to a `libSDL2.a` that
+  // the system provides) if `SDL2_STATIC` is set.
+  println!("cargo:rerun-if-env-changed=SDL2_CONFIG");
+  println!("cargo:rerun-if-env-changed=SDL2_LIB_DIR");
+  println!("cargo:rerun-if-env-changed=SDL2_STATIC");
+  println!("cargo:rerun-if-env-changed=PKG_CONFIG");
+  println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+
+  let link_static = env_flag("SDL2_STATIC");
+  if link_static {
+    println!("Performing statically-linked build against the system SDL2.");
+  } else {
+    println!("Performing dynamically-linked build against the system SDL2.");
+  }
+  link_extension_libs(link_static);
 
   let out_dir = env::var("OUT_DIR").unwrap();
   println!("out_dir:{}", out_dir);
@@ -146,45 +506,385 @@ fn do_dynamic_system() {
   let target_vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
   println!("target_vendor:{}", target_vendor);
 
-  if target_os.contains("windows") {
-    if target == "x86_64-pc-windows-msvc" {
-      let devel_path =
-        std::path::Path::new(&cargo_manifest_dir).join("SDL2-2.26.5-devel");
+  let sdl2_config = env::var_os("SDL2_CONFIG");
+  println!("sdl2_config:{:?}", sdl2_config);
+
+  let lib_dir = env::var_os("SDL2_LIB_DIR").map(std::path::PathBuf::from);
+  println!("lib_dir:{:?}", lib_dir);
 
+  if target_os.contains("windows") && sdl2_config.is_none() {
+    let lib_dir = match lib_dir {
+      Some(lib_dir) => lib_dir,
+      None if target == "x86_64-pc-windows-msvc" && !link_static => {
+        std::path::Path::new(&cargo_manifest_dir).join("SDL2-2.26.5-devel")
+      }
+      None => panic!("On windows, system builds without `SDL2_LIB_DIR` set are only supported for `x86_64-pc-windows-msvc` (and not with `SDL2_STATIC`). Consider the `static_bundled_build` feature instead."),
+    };
+    println!("cargo:rustc-link-search={}", lib_dir.display());
+
+    if link_static {
+      println!("cargo:rustc-link-lib=static=SDL2-static");
+      link_windows_system_libs();
+    } else {
       // Copy the DLL file into the executable out directory. When distributing
       // your executable, you might need to provide the SDL2 DLL as well, unless
       // it's already installed via other means (eg: steam provided it, or
       // something).
-      let dll_from = devel_path.join("SDL2.dll");
+      let dll_from = lib_dir.join("SDL2.dll");
       println!("dll_from:{}", dll_from.display());
-      let dll_to = std::path::Path::new(&out_dir).join("SDL2.dll");
-      println!("dll_to:{}", dll_to.display());
-      std::fs::copy(dll_from, dll_to).unwrap();
-
-      println!("cargo:rustc-link-search={}", devel_path.display());
+      if dll_from.exists() {
+        let dll_to = std::path::Path::new(&out_dir).join("SDL2.dll");
+        println!("dll_to:{}", dll_to.display());
+        std::fs::copy(dll_from, dll_to).unwrap();
+      }
       println!("cargo:rustc-link-lib=SDL2");
+    }
+
+    // The bundled dev files go with the bundled headers.
+    let bundled = lib_dir.ends_with("SDL2-2.26.5-devel");
+    let include = if bundled {
+      Some(
+        std::path::Path::new(&cargo_manifest_dir)
+          .join("SDL2-2.26.5")
+          .join("include"),
+      )
     } else {
-      panic!("On windows, dynamic_system builds are only supported for `x86_64-pc-windows-msvc`. Consider the `static_bundled_build` feature instead.");
+      // the official dev files have `include` next to `lib/x64`
+      lib_dir
+        .ancestors()
+        .skip(1)
+        .take(2)
+        .map(|p| p.join("include"))
+        .find(|p| p.join("SDL.h").exists())
+    };
+    export_metadata(
+      include.as_deref(),
+      Some(&lib_dir),
+      if bundled { Some("2.26.5") } else { None },
+      link_static,
+    );
+  } else {
+    // Searched before any paths that a config tool gives.
+    if let Some(lib_dir) = &lib_dir {
+      println!("cargo:rustc-link-search=native={}", lib_dir.display());
     }
+
+    let tool = ConfigTool::find(sdl2_config.as_deref());
+    let (include, found_lib_dir, version) = match &tool {
+      Some(tool) => {
+        let version = tool.version();
+        if let Some(version) = &version {
+          check_system_version(version);
+        }
+        let sd2_config_linking_stdout = tool
+          .libs(link_static)
+          .expect("The config tool couldn't give the SDL2 linker flags.");
+        let found_lib_dir =
+          flag_values(&sd2_config_linking_stdout, "-L").next();
+        process_sdl2_config_output(sd2_config_linking_stdout);
+        // `sdl2.pc` gives both `<prefix>/include` and `<prefix>/include/SDL2`,
+        // so use the one that actually has the headers.
+        let include = tool.cflags().and_then(|out| {
+          flag_values(&out, "-I").find(|dir| dir.join("SDL.h").exists())
+        });
+        (include, found_lib_dir, version)
+      }
+      None if lib_dir.is_some() => {
+        println!("No config tool found, linking from `SDL2_LIB_DIR` alone.");
+        (None, None, None)
+      }
+      None => panic!("Couldn't find SDL2 with `pkg-config` or `sdl2-config`, is SDL2 properly installed? If it's somewhere those tools don't know about, set `SDL2_CONFIG` to the path of its `sdl2-config`, or set `SDL2_LIB_DIR` to the folder holding the library."),
+    };
+    if link_static {
+      println!("cargo:rustc-link-lib=static=SDL2");
+    } else {
+      println!("cargo:rustc-link-lib=SDL2");
+    }
+
+    let lib_dir = lib_dir.or(found_lib_dir);
+    // Without a config tool, guess at the usual layout of an install prefix.
+    let include = include.or_else(|| {
+      let guess = lib_dir.as_ref()?.parent()?.join("include").join("SDL2");
+      guess.join("SDL.h").exists().then_some(guess)
+    });
+    export_metadata(
+      include.as_deref(),
+      lib_dir.as_deref(),
+      version.as_deref(),
+      link_static,
+    );
+  }
+}
+
+/// If an env var is set to anything other than empty, `0`, or `false`.
+#[cfg(not(feature = "static_bundled_build"))]
+fn env_flag(name: &str) -> bool {
+  match env::var(name) {
+    Ok(val) => !matches!(val.trim(), "" | "0" | "false"),
+    Err(_) => false,
+  }
+}
+
+/// Runs a config tool, giving its output if it ran successfully.
+fn run_config_tool(program: &std::ffi::OsStr, args: &[&str]) -> Option<String> {
+  let output = std::process::Command::new(program).args(args).output().ok()?;
+  if !output.status.success() {
+    return None;
+  }
+  let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+  if stdout.trim().len() > 0 {
+    Some(stdout)
   } else {
-    let sd2_config_linking = std::process::Command::new("sdl2-config")
-      .arg("--libs")
-      .output()
-      .unwrap_or_else(|_| {
-        panic!("Couldn't run `sdl2-config --libs`, is SDL2 properly installed?")
-      });
-    assert!(sd2_config_linking.status.success());
+    None
+  }
+}
 
-    let sd2_config_linking_stdout: String =
-      String::from_utf8_lossy(&sd2_config_linking.stdout).into_owned();
-    println!("sd2_config_linking_stdout: {}", sd2_config_linking_stdout);
-    assert!(sd2_config_linking_stdout.len() > 0);
+/// A tool that can say how to use the system SDL2.
+#[cfg(not(feature = "static_bundled_build"))]
+enum ConfigTool {
+  /// `sdl2-config`, or whatever `SDL2_CONFIG` points to.
+  Sdl2Config(std::ffi::OsString),
+  /// `pkg-config` (or whatever `PKG_CONFIG` points to), asking about `sdl2`.
+  PkgConfig(std::ffi::OsString),
+}
+#[cfg(not(feature = "static_bundled_build"))]
+impl ConfigTool {
+  /// Tries `SDL2_CONFIG` (if set), then `pkg-config`, then `sdl2-config`.
+  fn find(sdl2_config: Option<&std::ffi::OsStr>) -> Option<Self> {
+    if let Some(sdl2_config) = sdl2_config {
+      // The user asked for this one specifically, so don't quietly skip it.
+      let tool = ConfigTool::Sdl2Config(sdl2_config.into());
+      if tool.version().is_none() {
+        panic!("Couldn't run `{:?} --version`.", sdl2_config);
+      }
+      return Some(tool);
+    }
+    let pkg_config =
+      env::var_os("PKG_CONFIG").unwrap_or_else(|| "pkg-config".into());
+    [
+      ConfigTool::PkgConfig(pkg_config),
+      ConfigTool::Sdl2Config("sdl2-config".into()),
+    ]
+    .into_iter()
+    .find(|tool| tool.version().is_some())
+  }
 
-    process_sdl2_config_output(sd2_config_linking_stdout);
-    println!("cargo:rustc-link-lib=SDL2");
+  fn run(
+    &self, sdl2_config_args: &[&str], pkg_config_args: &[&str],
+  ) -> Option<String> {
+    let out = match self {
+      ConfigTool::Sdl2Config(program) => {
+        run_config_tool(program, sdl2_config_args)
+      }
+      ConfigTool::PkgConfig(program) => {
+        run_config_tool(program, pkg_config_args)
+      }
+    };
+    println!("config_tool_output: {:?}", out);
+    out
+  }
+
+  /// The linker flags.
+  fn libs(&self, link_static: bool) -> Option<String> {
+    if link_static {
+      self.run(&["--static-libs"], &["--libs", "--static", "sdl2"])
+    } else {
+      self.run(&["--libs"], &["--libs", "sdl2"])
+    }
+  }
+
+  /// The C compiler flags.
+  fn cflags(&self) -> Option<String> {
+    self.run(&["--cflags"], &["--cflags", "sdl2"])
+  }
+
+  /// The version of SDL2, eg: `2.26.5`
+  fn version(&self) -> Option<String> {
+    self
+      .run(&["--version"], &["--modversion", "sdl2"])
+      .map(|out| out.trim().to_string())
   }
 }
 
+/// Each value of a flag (eg: `-I`) in some config tool output, in order.
+#[cfg(not(feature = "static_bundled_build"))]
+fn flag_values<'a>(
+  output: &'a str, flag: &'a str,
+) -> impl Iterator<Item = std::path::PathBuf> + 'a {
+  output
+    .split_whitespace()
+    .filter_map(move |term| term.strip_prefix(flag))
+    .map(Into::into)
+}
+
+/// Sets the `links` metadata, which packages depending on this one can read
+/// as `DEP_SDL2_INCLUDE`, `DEP_SDL2_LIB_DIR`, `DEP_SDL2_VERSION` and
+/// `DEP_SDL2_STATIC`.
+///
+/// `include` is the folder that holds `SDL.h`.
+fn export_metadata(
+  include: Option<&std::path::Path>, lib_dir: Option<&std::path::Path>,
+  version: Option<&str>, link_static: bool,
+) {
+  if let Some(include) = include {
+    println!("cargo:include={}", include.display());
+  }
+  if let Some(lib_dir) = lib_dir {
+    println!("cargo:lib_dir={}", lib_dir.display());
+  }
+  if let Some(version) = version {
+    println!("cargo:version={}", version);
+    match parse_version(version) {
+      Some(v) => {
+        for &(cfg_v, cfg, _) in SDL_VERSIONS {
+          if v >= cfg_v {
+            println!("cargo:rustc-cfg={}", cfg);
+          }
+        }
+      }
+      None => println!("Couldn't parse the SDL2 version: {:?}", version),
+    }
+  }
+  println!("cargo:static={}", link_static);
+}
+
+/// Each SDL2 version with a cargo feature, along with the cfg that's set when
+/// the SDL2 being built against is at least that new.
+const SDL_VERSIONS: &[((u32, u32, u32), &str, &str)] = &[
+  ((2, 0, 16), "fermium_sdl_2_0_16", "sdl_2_0_16"),
+  ((2, 0, 18), "fermium_sdl_2_0_18", "sdl_2_0_18"),
+  ((2, 0, 20), "fermium_sdl_2_0_20", "sdl_2_0_20"),
+  ((2, 0, 22), "fermium_sdl_2_0_22", "sdl_2_0_22"),
+  ((2, 24, 0), "fermium_sdl_2_24", "sdl_2_24"),
+  ((2, 26, 0), "fermium_sdl_2_26", "sdl_2_26"),
+];
+
+/// Parses `major.minor.patch`, ignoring anything after the patch number.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+  let mut parts = version.trim().splitn(3, '.');
+  let major = parts.next()?.parse().ok()?;
+  let minor = parts.next()?.parse().ok()?;
+  let patch: String =
+    parts.next()?.chars().take_while(|c| c.is_ascii_digit()).collect();
+  Some((major, minor, patch.parse().ok()?))
+}
+
+/// Panics with an explanation if the system SDL2 is too old for the enabled
+/// features, rather than letting the linker fail with a pile of undefined
+/// symbols.
+#[cfg(not(feature = "static_bundled_build"))]
+fn check_system_version(version: &str) {
+  let found = match parse_version(version) {
+    Some(found) => found,
+    None => return,
+  };
+  let enabled_features = [
+    cfg!(feature = "sdl_2_0_16"),
+    cfg!(feature = "sdl_2_0_18"),
+    cfg!(feature = "sdl_2_0_20"),
+    cfg!(feature = "sdl_2_0_22"),
+    cfg!(feature = "sdl_2_24"),
+    cfg!(feature = "sdl_2_26"),
+  ];
+  let (needed, why) = SDL_VERSIONS
+    .iter()
+    .zip(enabled_features)
+    .rev()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(&(v, _, feature), _)| {
+      (v, format!("the `{}` feature is enabled", feature))
+    })
+    .next()
+    .unwrap_or((
+      (2, 0, 14),
+      "that's the oldest version fermium supports".to_string(),
+    ));
+  if found < needed {
+    panic!(
+      "The system SDL2 is version {}, but at least {}.{}.{} is needed because {}. Update the system SDL2, turn off the newer `sdl_*` features, or use the `static_bundled_build` feature.",
+      version, needed.0, needed.1, needed.2, why
+    );
+  }
+}
+
+/// The SDL2 extension libraries enabled by cargo features, as the library
+/// name (which is also the `pkg-config` package name) and the prefix of the
+/// environment variables that affect it.
+fn extension_libs() -> Vec<(&'static str, &'static str)> {
+  let mut libs = Vec::new();
+  if cfg!(feature = "image") {
+    libs.push(("SDL2_image", "SDL2_IMAGE"));
+  }
+  if cfg!(feature = "ttf") {
+    libs.push(("SDL2_ttf", "SDL2_TTF"));
+  }
+  if cfg!(feature = "mixer") {
+    libs.push(("SDL2_mixer", "SDL2_MIXER"));
+  }
+  if cfg!(feature = "net") {
+    libs.push(("SDL2_net", "SDL2_NET"));
+  }
+  libs
+}
+
+/// Links the enabled extension libraries from the system.
+///
+/// This has to happen before SDL2 itself is linked, since they depend on it.
+/// They're linked the same way as SDL2 (static or not), so that there's only
+/// ever one copy of SDL2 in the program. If `pkg-config` knows the library
+/// then it says where to find it (and, when static linking, what else to
+/// link), otherwise the library is expected to be in the usual linker search
+/// paths or in `<PREFIX>_LIB_DIR`.
+fn link_extension_libs(link_static: bool) {
+  let pkg_config =
+    env::var_os("PKG_CONFIG").unwrap_or_else(|| "pkg-config".into());
+  for (lib, env_prefix) in extension_libs() {
+    let lib_dir_var = format!("{}_LIB_DIR", env_prefix);
+    println!("cargo:rerun-if-env-changed={}", lib_dir_var);
+    if let Some(lib_dir) = env::var_os(&lib_dir_var).filter(|d| !d.is_empty()) {
+      println!(
+        "cargo:rustc-link-search=native={}",
+        std::path::Path::new(&lib_dir).display()
+      );
+    }
+
+    // This goes before whatever the lib itself depends on.
+    if link_static {
+      println!("cargo:rustc-link-lib=static={}", lib);
+    } else {
+      println!("cargo:rustc-link-lib={}", lib);
+    }
+    let mut args = vec!["--libs"];
+    if link_static {
+      args.push("--static");
+    }
+    args.push(lib);
+    match run_config_tool(&pkg_config, &args) {
+      Some(libs) => {
+        println!("{}_libs: {}", lib, libs.trim());
+        // SDL2 itself (and this lib) are skipped by this.
+        process_sdl2_config_output(libs);
+      }
+      None => println!("`pkg-config` doesn't know about {}.", lib),
+    }
+  }
+}
+
+/// The system libraries SDL2 needs when static linking on Windows.
+fn link_windows_system_libs() {
+  println!("cargo:rustc-link-lib=user32");
+  println!("cargo:rustc-link-lib=gdi32");
+  println!("cargo:rustc-link-lib=winmm");
+  println!("cargo:rustc-link-lib=imm32");
+  println!("cargo:rustc-link-lib=ole32");
+  println!("cargo:rustc-link-lib=oleaut32");
+  println!("cargo:rustc-link-lib=version");
+  println!("cargo:rustc-link-lib=uuid");
+  println!("cargo:rustc-link-lib=advapi32");
+  println!("cargo:rustc-link-lib=setupapi");
+  println!("cargo:rustc-link-lib=shell32");
+}
+
 /// THE CALLER HAS TO HANDLE THE LINK TO SDL2 ITSELF (static or dynamic).
 fn process_sdl2_config_output(sd2_config_linking_stdout: String) {
   for term in sd2_config_linking_stdout.split_whitespace() {
@@ -20,8 +20,6 @@ unsafe fn print_ptr(mut p: *const u8) {
 /// # Safety
 /// get out of my face clippy
 pub unsafe fn set_controller_use_button_labels(labels: bool) -> bool {
-  const SDL_HINT_GAMECONTROLLER_USE_BUTTON_LABELS: &[u8] =
-    b"SDL_GAMECONTROLLER_USE_BUTTON_LABELS\0";
   let value: &[u8] = if labels { b"1\0" } else { b"0\0" };
   unsafe {
     SDL_SetHint(
@@ -50,32 +48,35 @@ fn main() {
     let num_joysticks = SDL_NumJoysticks();
     println!("SDL2 reports num_joysticks: {}", num_joysticks);
 
-    let mut event = SDL_Event::default();
     loop {
-      assert_eq!(SDL_WaitEvent(&mut event), 1);
-      match event.type_ {
-        SDL_QUIT => {
-          println!("SDL_QUIT");
+      let event = match wait_timeout(-1) {
+        Some(event) => event,
+        None => {
+          print!("Error while waiting: ");
+          print_error();
+          println!();
           break;
         }
-        SDL_KEYDOWN => {
-          println!("SDL_KEYDOWN: {:?}", event.key);
-        }
-        SDL_KEYUP => {
-          println!("SDL_KEYUP {:?}", event.key);
+      };
+      match event {
+        Event::Quit(_) => {
+          println!("SDL_QUIT");
+          break;
         }
-        SDL_CONTROLLERAXISMOTION => {
-          println!("SDL_CONTROLLERAXISMOTION: {:?}", event.caxis);
+        Event::Keyboard(key) => {
+          println!("{:?}", key);
         }
-        SDL_CONTROLLERBUTTONDOWN => {
-          println!("SDL_CONTROLLERBUTTONDOWN: {:?}", event.cbutton);
+        Event::ControllerAxis(caxis) => {
+          println!("{:?}", caxis);
         }
-        SDL_CONTROLLERBUTTONUP => {
-          println!("SDL_CONTROLLERBUTTONUP: {:?}", event.cbutton);
+        Event::ControllerButton(cbutton) => {
+          println!("{:?}", cbutton);
         }
-        SDL_CONTROLLERDEVICEADDED => {
-          println!("SDL_CONTROLLERDEVICEADDED: {:?}", event.cdevice);
-          let id = event.cdevice.which;
+        Event::ControllerDevice(cdevice)
+          if cdevice.type_ == SDL_CONTROLLERDEVICEADDED =>
+        {
+          println!("SDL_CONTROLLERDEVICEADDED: {:?}", cdevice);
+          let id = cdevice.which;
           println!("Opening joystick {} as a controller...", id);
           let controller = SDL_GameControllerOpen(id);
           if controller.is_null() {
@@ -127,9 +128,11 @@ fn main() {
             }
           }
         }
-        SDL_CONTROLLERDEVICEREMOVED => {
-          println!("SDL_CONTROLLERDEVICEREMOVED: {:?}", event.cdevice);
-          let id = event.cdevice.which;
+        Event::ControllerDevice(cdevice)
+          if cdevice.type_ == SDL_CONTROLLERDEVICEREMOVED =>
+        {
+          println!("SDL_CONTROLLERDEVICEREMOVED: {:?}", cdevice);
+          let id = cdevice.which;
           println!("Closing ID {}...", id);
           let controller = SDL_GameControllerFromInstanceID(SDL_JoystickID(id));
           if controller.is_null() {
@@ -138,20 +141,14 @@ fn main() {
             SDL_GameControllerClose(controller);
           }
         }
-        SDL_CONTROLLERDEVICEREMAPPED => {
-          println!("SDL_CONTROLLERDEVICEREMAPPED: {:?}", event.cdevice);
-        }
-        SDL_CONTROLLERTOUCHPADDOWN => {
-          println!("SDL_CONTROLLERTOUCHPADDOWN: {:?}", event.ctouchpad);
-        }
-        SDL_CONTROLLERTOUCHPADMOTION => {
-          println!("SDL_CONTROLLERTOUCHPADMOTION: {:?}", event.ctouchpad);
+        Event::ControllerDevice(cdevice) => {
+          println!("{:?}", cdevice);
         }
-        SDL_CONTROLLERTOUCHPADUP => {
-          println!("SDL_CONTROLLERTOUCHPADUP: {:?}", event.ctouchpad);
+        Event::ControllerTouchpad(ctouchpad) => {
+          println!("{:?}", ctouchpad);
         }
-        SDL_CONTROLLERSENSORUPDATE => {
-          println!("SDL_CONTROLLERSENSORUPDATE: {:?}", event.ctouchpad);
+        Event::ControllerSensor(csensor) => {
+          println!("{:?}", csensor);
         }
         _ => (),
       }
@@ -2,6 +2,14 @@
 
 use crate::{c_char, c_int, c_void, rwops::*, stdinc::*};
 
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+#[allow(unused)]
+use crate::hints::*;
+#[allow(unused)]
+use crate::*;
+
 /// Audio format flags.
 ///
 /// Use the appropriate const functions to query the bits.
@@ -18,7 +26,7 @@ use crate::{c_char, c_int, c_void, rwops::*, stdinc::*};
 /// 15 14 13 12 11 10 09 08 07 06 05 04 03 02 01 00
 /// ```
 /// (Unspecified bits are always zero.)
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_AudioFormat(pub u16);
 
@@ -104,6 +112,26 @@ pub const AUDIO_S32SYS: SDL_AudioFormat =
 /// Native-endian f32 samples
 pub const AUDIO_F32SYS: SDL_AudioFormat =
   if cfg!(target_endian = "little") { AUDIO_F32LSB } else { AUDIO_F32MSB };
+impl_names_for_tuple_newtype!(SDL_AudioFormat {
+  AUDIO_U8,
+  AUDIO_S8,
+  AUDIO_U16LSB,
+  AUDIO_S16LSB,
+  AUDIO_U16MSB,
+  AUDIO_S16MSB,
+  AUDIO_U16,
+  AUDIO_S16,
+  AUDIO_S32LSB,
+  AUDIO_S32MSB,
+  AUDIO_S32,
+  AUDIO_F32LSB,
+  AUDIO_F32MSB,
+  AUDIO_F32,
+  AUDIO_U16SYS,
+  AUDIO_S16SYS,
+  AUDIO_S32SYS,
+  AUDIO_F32SYS,
+});
 
 /// This function is called when the audio device needs more data.
 ///
@@ -214,7 +242,7 @@ pub const SDL_AUDIOCVT_MAX_FILTERS: u32 = 9;
 pub struct SDL_AudioDeviceID(pub u32);
 
 /// The status of an audio device.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_AudioStatus(pub u32);
 #[allow(missing_docs)]
@@ -223,6 +251,11 @@ pub const SDL_AUDIO_STOPPED: SDL_AudioStatus = SDL_AudioStatus(0);
 pub const SDL_AUDIO_PLAYING: SDL_AudioStatus = SDL_AudioStatus(1);
 #[allow(missing_docs)]
 pub const SDL_AUDIO_PAUSED: SDL_AudioStatus = SDL_AudioStatus(2);
+impl_names_for_tuple_newtype!(SDL_AudioStatus {
+  SDL_AUDIO_STOPPED,
+  SDL_AUDIO_PLAYING,
+  SDL_AUDIO_PAUSED,
+});
 
 /// SDL_AudioStream is a new audio conversion interface.
 ///
@@ -238,14 +271,31 @@ pub struct SDL_AudioStream(c_void);
 /// Maximum volume value, for use with [`SDL_MixAudio`].
 pub const SDL_MIX_MAXVOLUME: c_int = 128;
 
-extern "C" {
+sdl_extern! {
   /// The number of audio drivers. See [`SDL_GetAudioDriver`]
   pub fn SDL_GetNumAudioDrivers() -> c_int;
 
   /// The name of an audio driver. See [`SDL_GetAudioDriver`]
   pub fn SDL_GetAudioDriver(index: c_int) -> *const c_char;
 
-  // skipped because they're mostly internal use: SDL_AudioInit / SDL_AudioQuit
+  /// Initialize a particular audio driver.
+  ///
+  /// Normally you should just use [`SDL_Init`] or [`SDL_InitSubSystem`] with
+  /// [`SDL_INIT_AUDIO`], which picks the first driver that works (or the one
+  /// named by [`SDL_HINT_AUDIODRIVER`]). Use this when you specifically need
+  /// to choose the driver at runtime. An audio system started this way is shut
+  /// down with [`SDL_AudioQuit`].
+  ///
+  /// * `driver_name` the name of the desired audio driver, or NULL for the
+  ///   default.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  ///
+  /// See Also: [`audio_init_first_of`]
+  pub fn SDL_AudioInit(driver_name: *const c_char) -> c_int;
+
+  /// Shut down the audio subsystem started with [`SDL_AudioInit`].
+  pub fn SDL_AudioQuit();
 
   /// The name of the current audio driver (or null if no driver is
   /// initialized).
@@ -743,9 +793,67 @@ extern "C" {
   /// * **Returns:** 0 on success, nonzero on error
   ///
   /// See Also: [SDL_GetNumAudioDevices]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_GetAudioDeviceSpec(
     index: c_int, iscapture: c_int, spec: *mut SDL_AudioSpec,
   ) -> c_int;
+
+  /// Get the name and preferred format of the default audio device.
+  ///
+  /// Some (but not all!) platforms have an isolated mechanism to get
+  /// information about the "default" device. This can actually be a completely
+  /// different device that's not in the list you get from
+  /// [SDL_GetAudioDeviceSpec]. It can even be a network address!
+  ///
+  /// As a result, this call is not guaranteed to be performant, as it can query
+  /// the sound server directly every time, unlike the other query functions.
+  /// You should call this function sparingly!
+  ///
+  /// `spec` will be filled with the sample rate, sample format, and channel
+  /// count, if a default device exists on the system. If `name` is provided, it
+  /// will be filled with either a dynamically-allocated UTF-8 string or NULL.
+  ///
+  /// * `name` A pointer to be filled with the name of the default device (can
+  ///   be NULL). Please call [`SDL_free`] when you are done with this pointer!
+  /// * `spec` The [SDL_AudioSpec] to be initialized by this function.
+  /// * `iscapture` non-zero to query the default recording device, zero to
+  ///   query the default output device.
+  /// * **Returns:** 0 on success, nonzero on error
+  ///
+  /// See Also: [SDL_GetAudioDeviceName], [SDL_GetAudioDeviceSpec]
+  #[cfg(feature = "sdl_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_24")))]
+  pub fn SDL_GetDefaultAudioInfo(
+    name: *mut *mut c_char, spec: *mut SDL_AudioSpec, iscapture: c_int,
+  ) -> c_int;
+}
+
+/// Tries each audio driver in order with [`SDL_AudioInit`], stopping at the
+/// first one that works.
+///
+/// **Returns:** The name of the driver that started, or `None` if they all
+/// failed (in which case [`SDL_GetErrorMsg`] has the error from the last driver
+/// tried).
+///
+/// For example, a server build that wants sound when possible but must always
+/// be able to start could pass
+/// `[c"pipewire", c"pulseaudio", c"alsa", c"dummy"]` (written with
+/// `CStr::from_bytes_with_nul` on older compilers). Because "dummy" is always
+/// compiled into SDL2 the fallback is deterministic.
+///
+/// Shut down the driver with [`SDL_AudioQuit`] when you're done.
+///
+/// ## Safety
+/// * Each [`SDL_AudioInit`] call first shuts down any audio driver that's
+///   already running, which closes all of its open audio devices. Any
+///   [`SDL_AudioDeviceID`] you're still holding is invalid afterwards.
+/// * This must not be called while another thread could be using the audio
+///   subsystem.
+pub unsafe fn audio_init_first_of<'a>(
+  drivers: &[&'a core::ffi::CStr],
+) -> Option<&'a core::ffi::CStr> {
+  drivers.iter().copied().find(|driver| SDL_AudioInit(driver.as_ptr()) == 0)
 }
 
 /// Loads a WAV file.
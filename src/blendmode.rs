@@ -3,7 +3,7 @@
 /// A newtype'd blend operation value.
 ///
 /// See `SDL_BLENDMODE_*` for examples.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_BlendMode(pub u32);
 
@@ -38,11 +38,19 @@ pub const SDL_BLENDMODE_MOD: SDL_BlendMode = SDL_BlendMode(0x00000004);
 pub const SDL_BLENDMODE_MUL: SDL_BlendMode = SDL_BlendMode(0x00000008);
 /// Sentinel for an invalid blend mode.
 pub const SDL_BLENDMODE_INVALID: SDL_BlendMode = SDL_BlendMode(0x7FFFFFFF);
+impl_names_for_tuple_newtype!(SDL_BlendMode {
+  SDL_BLENDMODE_NONE,
+  SDL_BLENDMODE_BLEND,
+  SDL_BLENDMODE_ADD,
+  SDL_BLENDMODE_MOD,
+  SDL_BLENDMODE_MUL,
+  SDL_BLENDMODE_INVALID,
+});
 
 /// A newtype'd blend operation value.
 ///
 /// See `SDL_BLENDOPERATION_*` for examples.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_BlendOperation(pub u32);
 
@@ -60,11 +68,18 @@ pub const SDL_BLENDOPERATION_MINIMUM: SDL_BlendOperation =
 /// Op: max(dst, src) : supported by D3D11
 pub const SDL_BLENDOPERATION_MAXIMUM: SDL_BlendOperation =
   SDL_BlendOperation(0x5);
+impl_names_for_tuple_newtype!(SDL_BlendOperation {
+  SDL_BLENDOPERATION_ADD,
+  SDL_BLENDOPERATION_SUBTRACT,
+  SDL_BLENDOPERATION_REV_SUBTRACT,
+  SDL_BLENDOPERATION_MINIMUM,
+  SDL_BLENDOPERATION_MAXIMUM,
+});
 
 /// A newtype'd blend factor value.
 ///
 /// See the `SDL_BLENDFACTOR_*` constants for values of this type.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_BlendFactor(pub u32);
 
@@ -92,8 +107,20 @@ pub const SDL_BLENDFACTOR_DST_ALPHA: SDL_BlendFactor = SDL_BlendFactor(0x9);
 /// RGBA: 1-dstA, 1-dstA, 1-dstA, 1-dstA
 pub const SDL_BLENDFACTOR_ONE_MINUS_DST_ALPHA: SDL_BlendFactor =
   SDL_BlendFactor(0xA);
+impl_names_for_tuple_newtype!(SDL_BlendFactor {
+  SDL_BLENDFACTOR_ZERO,
+  SDL_BLENDFACTOR_ONE,
+  SDL_BLENDFACTOR_SRC_COLOR,
+  SDL_BLENDFACTOR_ONE_MINUS_SRC_COLOR,
+  SDL_BLENDFACTOR_SRC_ALPHA,
+  SDL_BLENDFACTOR_ONE_MINUS_SRC_ALPHA,
+  SDL_BLENDFACTOR_DST_COLOR,
+  SDL_BLENDFACTOR_ONE_MINUS_DST_COLOR,
+  SDL_BLENDFACTOR_DST_ALPHA,
+  SDL_BLENDFACTOR_ONE_MINUS_DST_ALPHA,
+});
 
-extern "C" {
+sdl_extern! {
   /// Create a custom blend mode, which may or may not be supported by a given
   /// renderer
   ///
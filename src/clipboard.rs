@@ -2,7 +2,7 @@
 
 use crate::{c_char, c_int, stdinc::*};
 
-extern "C" {
+sdl_extern! {
   /// Put UTF-8 text into the clipboard
   pub fn SDL_SetClipboardText(text: *const c_char) -> c_int;
 
@@ -12,4 +12,59 @@ extern "C" {
   /// Returns a flag indicating whether the clipboard exists and contains a text
   /// string that is non-empty.
   pub fn SDL_HasClipboardText() -> SDL_bool;
+
+  /// Put UTF-8 text into the primary selection.
+  ///
+  /// The primary selection is the X11/Wayland "select text, middle-click to
+  /// paste" buffer, which is separate from the clipboard.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  #[cfg(feature = "sdl_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_26")))]
+  pub fn SDL_SetPrimarySelectionText(text: *const c_char) -> c_int;
+
+  /// Get UTF-8 text from the primary selection, which must be freed with
+  /// [`SDL_free`] (even if there was an error).
+  ///
+  /// **Returns:** the primary selection text on success or an empty string on
+  /// failure.
+  #[cfg(feature = "sdl_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_26")))]
+  pub fn SDL_GetPrimarySelectionText() -> *mut c_char;
+
+  /// Returns a flag indicating whether the primary selection exists and
+  /// contains a text string that is non-empty.
+  #[cfg(feature = "sdl_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_26")))]
+  pub fn SDL_HasPrimarySelectionText() -> SDL_bool;
+}
+
+/// Gets the clipboard text as an owned `String`.
+///
+/// This calls [`SDL_GetClipboardText`] and then frees the SDL allocation for
+/// you. Invalid UTF-8 is replaced, and an empty clipboard gives an empty string.
+///
+/// ## Safety
+/// * The video subsystem must be initialized, and this must be used on the
+///   main thread (some platforms, such as macOS, require it).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub unsafe fn clipboard_text() -> alloc::string::String {
+  string_from_sdl_alloc(SDL_GetClipboardText()).unwrap_or_default()
+}
+
+/// Gets the primary selection text as an owned `String`.
+///
+/// This calls [`SDL_GetPrimarySelectionText`] and then frees the SDL allocation
+/// for you. Invalid UTF-8 is replaced, and an empty selection gives an empty
+/// string.
+///
+/// ## Safety
+/// * As [`clipboard_text`].
+#[cfg(all(feature = "alloc", feature = "sdl_2_26"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "alloc", feature = "sdl_2_26"))))]
+#[must_use]
+pub unsafe fn primary_selection_text() -> alloc::string::String {
+  string_from_sdl_alloc(SDL_GetPrimarySelectionText()).unwrap_or_default()
 }
@@ -2,7 +2,7 @@
 
 use crate::c_int;
 
-extern "C" {
+sdl_extern! {
   /// This function returns the number of CPU cores available.
   pub fn SDL_GetCPUCount() -> c_int;
 
@@ -0,0 +1,226 @@
+//! Loading SDL2 at runtime, for the `dynamic_loading` feature.
+//!
+//! With this feature the crate doesn't link to SDL2 at all. Instead every
+//! declared function calls through a table of function pointers, and [`load`]
+//! opens the library and fills in that table. This lets a program start up
+//! and report a friendly error when SDL2 isn't installed:
+//!
+//! ```no_run
+//! match unsafe { fermium::load(None) } {
+//!   Ok(()) => { /* SDL2 can be used now */ }
+//!   Err(e) => {
+//!     // tell the user to install SDL2, then exit.
+//!     let _ = e;
+//!   }
+//! }
+//! ```
+//!
+//! Calling any SDL2 function before a successful [`load`] will panic.
+
+use core::{
+  ffi::CStr,
+  fmt,
+  ptr::null_mut,
+  sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{c_char, c_int, c_void};
+
+#[cfg(unix)]
+mod sys {
+  use super::*;
+
+  #[cfg_attr(
+    any(target_os = "linux", target_os = "android"),
+    link(name = "dl")
+  )]
+  extern "C" {
+    fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlclose(handle: *mut c_void) -> c_int;
+  }
+
+  const RTLD_NOW: c_int = 2;
+
+  pub unsafe fn open(name: *const c_char) -> *mut c_void {
+    dlopen(name, RTLD_NOW)
+  }
+  pub unsafe fn symbol(
+    handle: *mut c_void, name: *const c_char,
+  ) -> *mut c_void {
+    dlsym(handle, name)
+  }
+  pub unsafe fn close(handle: *mut c_void) {
+    dlclose(handle);
+  }
+}
+
+#[cfg(windows)]
+mod sys {
+  use super::*;
+
+  #[link(name = "kernel32")]
+  extern "system" {
+    fn LoadLibraryA(lpLibFileName: *const c_char) -> *mut c_void;
+    fn GetProcAddress(
+      hModule: *mut c_void, lpProcName: *const c_char,
+    ) -> *mut c_void;
+    fn FreeLibrary(hLibModule: *mut c_void) -> c_int;
+  }
+
+  pub unsafe fn open(name: *const c_char) -> *mut c_void {
+    LoadLibraryA(name)
+  }
+  pub unsafe fn symbol(
+    handle: *mut c_void, name: *const c_char,
+  ) -> *mut c_void {
+    GetProcAddress(handle, name)
+  }
+  pub unsafe fn close(handle: *mut c_void) {
+    FreeLibrary(handle);
+  }
+}
+
+/// The names [`load`] tries when it isn't given a path.
+#[cfg(windows)]
+const DEFAULT_LIBRARY_NAMES: &[&[u8]] = &[b"SDL2.dll\0"];
+#[cfg(target_vendor = "apple")]
+const DEFAULT_LIBRARY_NAMES: &[&[u8]] =
+  &[b"libSDL2-2.0.0.dylib\0", b"libSDL2.dylib\0", b"SDL2.framework/SDL2\0"];
+#[cfg(not(any(windows, target_vendor = "apple")))]
+const DEFAULT_LIBRARY_NAMES: &[&[u8]] =
+  &[b"libSDL2-2.0.so.0\0", b"libSDL2-2.0.so\0", b"libSDL2.so\0"];
+
+/// The handle of the loaded library, or null.
+static LIBRARY: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+
+/// The reasons that [`load`] can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadError {
+  /// The library couldn't be opened. Usually this means that SDL2 isn't
+  /// installed, or isn't where the path said it would be.
+  LibraryNotFound,
+  /// The library opened, but it doesn't have this function. Usually this means
+  /// that it's older than the `sdl_*` version features you've enabled.
+  MissingFunction(&'static str),
+}
+impl fmt::Display for LoadError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LoadError::LibraryNotFound => {
+        f.write_str("couldn't open the SDL2 library")
+      }
+      LoadError::MissingFunction(name) => {
+        write!(f, "the SDL2 library is missing the `{name}` function")
+      }
+    }
+  }
+}
+
+/// Opens the SDL2 library and fills in the function table.
+///
+/// * `path` is passed to the system's library loader (`dlopen` or
+///   `LoadLibraryA`). If it's `None` then the usual names of the SDL2 library
+///   for the platform are tried in turn.
+///
+/// If a library has already been loaded this does nothing and returns `Ok`.
+///
+/// If the library opens but is missing any of the declared functions it's
+/// closed again, and the table is left empty.
+///
+/// ## Safety
+/// * The library found must actually be SDL2, or the declarations won't match
+///   the functions they're calling.
+/// * This must not be called while another thread could be calling SDL2
+///   functions or `load`.
+pub unsafe fn load(path: Option<&CStr>) -> Result<(), LoadError> {
+  if is_loaded() {
+    return Ok(());
+  }
+  let handle = match path {
+    Some(path) => sys::open(path.as_ptr()),
+    None => DEFAULT_LIBRARY_NAMES
+      .iter()
+      .map(|name| sys::open(name.as_ptr().cast()))
+      .find(|handle| !handle.is_null())
+      .unwrap_or(null_mut()),
+  };
+  if handle.is_null() {
+    return Err(LoadError::LibraryNotFound);
+  }
+  let mut resolve =
+    |name: &'static str| sys::symbol(handle, name.as_ptr().cast());
+  if let Err(name) = load_all_fn_tables(&mut resolve) {
+    // clear out anything that was found before we close the library.
+    let _ = load_all_fn_tables(&mut |_| null_mut());
+    sys::close(handle);
+    return Err(LoadError::MissingFunction(name));
+  }
+  LIBRARY.store(handle, Ordering::Release);
+  Ok(())
+}
+
+/// If SDL2 has been successfully loaded.
+#[inline]
+#[must_use]
+pub fn is_loaded() -> bool {
+  !LIBRARY.load(Ordering::Acquire).is_null()
+}
+
+/// Looks up a symbol in the loaded library, or gives null.
+pub(crate) fn symbol(name: *const c_char) -> *mut c_void {
+  let handle = LIBRARY.load(Ordering::Acquire);
+  if handle.is_null() {
+    null_mut()
+  } else {
+    unsafe { sys::symbol(handle, name) }
+  }
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn not_loaded(name: &str) -> ! {
+  panic!("`{name}` was called before `fermium::load` succeeded")
+}
+
+/// Fills every module's table, giving the first missing symbol.
+fn load_all_fn_tables(
+  resolve: &mut dyn FnMut(&'static str) -> *mut c_void,
+) -> Result<(), &'static str> {
+  use crate::*;
+  let results = [
+    crate::load_fn_table(resolve),
+    audio::load_fn_table(resolve),
+    blendmode::load_fn_table(resolve),
+    clipboard::load_fn_table(resolve),
+    cpuinfo::load_fn_table(resolve),
+    error::load_fn_table(resolve),
+    events::load_fn_table(resolve),
+    filesystem::load_fn_table(resolve),
+    gamecontroller::load_fn_table(resolve),
+    gesture::load_fn_table(resolve),
+    hints::load_fn_table(resolve),
+    joystick::load_fn_table(resolve),
+    keyboard::load_fn_table(resolve),
+    loadso::load_fn_table(resolve),
+    messagebox::load_fn_table(resolve),
+    mouse::load_fn_table(resolve),
+    pixels::load_fn_table(resolve),
+    platform::load_fn_table(resolve),
+    power::load_fn_table(resolve),
+    rect::load_fn_table(resolve),
+    renderer::load_fn_table(resolve),
+    rwops::load_fn_table(resolve),
+    sensor::load_fn_table(resolve),
+    stdinc::load_fn_table(resolve),
+    surface::load_fn_table(resolve),
+    syswm::load_fn_table(resolve),
+    timer::load_fn_table(resolve),
+    touch::load_fn_table(resolve),
+    version::load_fn_table(resolve),
+    video::load_fn_table(resolve),
+    #[cfg(feature = "vulkan")]
+    vulkan::load_fn_table(resolve),
+  ];
+  results.into_iter().collect()
+}
@@ -2,7 +2,7 @@
 
 use crate::{c_char, c_int};
 
-extern "C" {
+sdl_extern! {
   /// Get the last error message that was set for the current thread.
   ///
   /// SDL API functions may set error messages and then succeed, so you should
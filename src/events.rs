@@ -9,6 +9,8 @@ use crate::{
 #[allow(unused)]
 use crate::audio::*;
 #[allow(unused)]
+use crate::error::*;
+#[allow(unused)]
 use crate::sensor::*;
 #[allow(unused)]
 use crate::timer::*;
@@ -24,7 +26,7 @@ pub const SDL_RELEASED: u8 = 0;
 pub const SDL_PRESSED: u8 = 1;
 
 /// The types of events that can be delivered.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_EventType(pub i32);
 
@@ -217,6 +219,64 @@ pub const SDL_USEREVENT: SDL_EventType = SDL_EventType(0x8000);
 
 /// This last event is only for bounding internal arrays.
 pub const SDL_LASTEVENT: SDL_EventType = SDL_EventType(0xFFFF);
+impl_names_for_tuple_newtype!(SDL_EventType {
+  SDL_FIRSTEVENT,
+  SDL_QUIT,
+  SDL_APP_TERMINATING,
+  SDL_APP_LOWMEMORY,
+  SDL_APP_WILLENTERBACKGROUND,
+  SDL_APP_DIDENTERBACKGROUND,
+  SDL_APP_WILLENTERFOREGROUND,
+  SDL_APP_DIDENTERFOREGROUND,
+  SDL_LOCALECHANGED,
+  SDL_DISPLAYEVENT,
+  SDL_WINDOWEVENT,
+  SDL_SYSWMEVENT,
+  SDL_KEYDOWN,
+  SDL_KEYUP,
+  SDL_TEXTEDITING,
+  SDL_TEXTINPUT,
+  SDL_KEYMAPCHANGED,
+  SDL_MOUSEMOTION,
+  SDL_MOUSEBUTTONDOWN,
+  SDL_MOUSEBUTTONUP,
+  SDL_MOUSEWHEEL,
+  SDL_JOYAXISMOTION,
+  SDL_JOYBALLMOTION,
+  SDL_JOYHATMOTION,
+  SDL_JOYBUTTONDOWN,
+  SDL_JOYBUTTONUP,
+  SDL_JOYDEVICEADDED,
+  SDL_JOYDEVICEREMOVED,
+  SDL_CONTROLLERAXISMOTION,
+  SDL_CONTROLLERBUTTONDOWN,
+  SDL_CONTROLLERBUTTONUP,
+  SDL_CONTROLLERDEVICEADDED,
+  SDL_CONTROLLERDEVICEREMOVED,
+  SDL_CONTROLLERDEVICEREMAPPED,
+  SDL_CONTROLLERTOUCHPADDOWN,
+  SDL_CONTROLLERTOUCHPADMOTION,
+  SDL_CONTROLLERTOUCHPADUP,
+  SDL_CONTROLLERSENSORUPDATE,
+  SDL_FINGERDOWN,
+  SDL_FINGERUP,
+  SDL_FINGERMOTION,
+  SDL_DOLLARGESTURE,
+  SDL_DOLLARRECORD,
+  SDL_MULTIGESTURE,
+  SDL_CLIPBOARDUPDATE,
+  SDL_DROPFILE,
+  SDL_DROPTEXT,
+  SDL_DROPBEGIN,
+  SDL_DROPCOMPLETE,
+  SDL_AUDIODEVICEADDED,
+  SDL_AUDIODEVICEREMOVED,
+  SDL_SENSORUPDATE,
+  SDL_RENDER_TARGETS_RESET,
+  SDL_RENDER_DEVICE_RESET,
+  SDL_USEREVENT,
+  SDL_LASTEVENT,
+});
 
 /// Fields shared by every event.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -882,8 +942,146 @@ impl Default for SDL_Event {
   }
 }
 
+impl SDL_Event {
+  /// Checks the event's type and gives the matching union field.
+  ///
+  /// Types within [`SDL_USEREVENT`] .. [`SDL_LASTEVENT`] are always
+  /// [`Event::User`], and any types that fermium doesn't know about are
+  /// [`Event::Unknown`].
+  #[must_use]
+  pub fn decode(&self) -> Event {
+    // Safety: every field starts with the type, and the type says which of
+    // the other fields was written.
+    unsafe {
+      match self.type_ {
+        SDL_QUIT => Event::Quit(self.quit),
+        SDL_APP_TERMINATING
+        | SDL_APP_LOWMEMORY
+        | SDL_APP_WILLENTERBACKGROUND
+        | SDL_APP_DIDENTERBACKGROUND
+        | SDL_APP_WILLENTERFOREGROUND
+        | SDL_APP_DIDENTERFOREGROUND
+        | SDL_LOCALECHANGED
+        | SDL_KEYMAPCHANGED
+        | SDL_CLIPBOARDUPDATE
+        | SDL_RENDER_TARGETS_RESET
+        | SDL_RENDER_DEVICE_RESET => Event::Common(self.common),
+        SDL_DISPLAYEVENT => Event::Display(self.display),
+        SDL_WINDOWEVENT => Event::Window(self.window),
+        SDL_SYSWMEVENT => Event::SysWM(self.syswm),
+        SDL_KEYDOWN | SDL_KEYUP => Event::Keyboard(self.key),
+        SDL_TEXTEDITING => Event::TextEditing(self.edit),
+        SDL_TEXTINPUT => Event::TextInput(self.text),
+        SDL_MOUSEMOTION => Event::MouseMotion(self.motion),
+        SDL_MOUSEBUTTONDOWN | SDL_MOUSEBUTTONUP => {
+          Event::MouseButton(self.button)
+        }
+        SDL_MOUSEWHEEL => Event::MouseWheel(self.wheel),
+        SDL_JOYAXISMOTION => Event::JoyAxis(self.jaxis),
+        SDL_JOYBALLMOTION => Event::JoyBall(self.jball),
+        SDL_JOYHATMOTION => Event::JoyHat(self.jhat),
+        SDL_JOYBUTTONDOWN | SDL_JOYBUTTONUP => Event::JoyButton(self.jbutton),
+        SDL_JOYDEVICEADDED | SDL_JOYDEVICEREMOVED => {
+          Event::JoyDevice(self.jdevice)
+        }
+        SDL_CONTROLLERAXISMOTION => Event::ControllerAxis(self.caxis),
+        SDL_CONTROLLERBUTTONDOWN | SDL_CONTROLLERBUTTONUP => {
+          Event::ControllerButton(self.cbutton)
+        }
+        SDL_CONTROLLERDEVICEADDED
+        | SDL_CONTROLLERDEVICEREMOVED
+        | SDL_CONTROLLERDEVICEREMAPPED => Event::ControllerDevice(self.cdevice),
+        SDL_CONTROLLERTOUCHPADDOWN
+        | SDL_CONTROLLERTOUCHPADMOTION
+        | SDL_CONTROLLERTOUCHPADUP => Event::ControllerTouchpad(self.ctouchpad),
+        SDL_CONTROLLERSENSORUPDATE => Event::ControllerSensor(self.csensor),
+        SDL_FINGERDOWN | SDL_FINGERUP | SDL_FINGERMOTION => {
+          Event::TouchFinger(self.tfinger)
+        }
+        SDL_DOLLARGESTURE | SDL_DOLLARRECORD => {
+          Event::DollarGesture(self.dgesture)
+        }
+        SDL_MULTIGESTURE => Event::MultiGesture(self.mgesture),
+        SDL_DROPFILE | SDL_DROPTEXT | SDL_DROPBEGIN | SDL_DROPCOMPLETE => {
+          Event::Drop(self.drop)
+        }
+        SDL_AUDIODEVICEADDED | SDL_AUDIODEVICEREMOVED => {
+          Event::AudioDevice(self.adevice)
+        }
+        SDL_SENSORUPDATE => Event::Sensor(self.sensor),
+        t if t.0 >= SDL_USEREVENT.0 && t.0 < SDL_LASTEVENT.0 => {
+          Event::User(self.user)
+        }
+        _ => Event::Unknown(self.common),
+      }
+    }
+  }
+}
+impl core::fmt::Debug for SDL_Event {
+  /// Formats as the [decoded](SDL_Event::decode) event.
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Debug::fmt(&self.decode(), f)
+  }
+}
+impl From<SDL_Event> for Event {
+  #[inline]
+  fn from(event: SDL_Event) -> Self {
+    event.decode()
+  }
+}
+
+/// An [`SDL_Event`] that's been decoded by its type.
+///
+/// Each variant holds a copy of the union field for that type. Some variants
+/// cover several event types (eg: [`SDL_KEYDOWN`] and [`SDL_KEYUP`] are both
+/// `Keyboard`), so check the `type_` field of the data to tell them apart.
+///
+/// See [`SDL_Event::decode`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum Event {
+  /// [`SDL_QUIT`]
+  Quit(SDL_QuitEvent),
+  /// Event types without any data of their own: the `SDL_APP_*` events,
+  /// [`SDL_LOCALECHANGED`], [`SDL_KEYMAPCHANGED`], [`SDL_CLIPBOARDUPDATE`],
+  /// [`SDL_RENDER_TARGETS_RESET`], and [`SDL_RENDER_DEVICE_RESET`].
+  Common(SDL_CommonEvent),
+  Display(SDL_DisplayEvent),
+  Window(SDL_WindowEvent),
+  SysWM(SDL_SysWMEvent),
+  Keyboard(SDL_KeyboardEvent),
+  TextEditing(SDL_TextEditingEvent),
+  TextInput(SDL_TextInputEvent),
+  MouseMotion(SDL_MouseMotionEvent),
+  MouseButton(SDL_MouseButtonEvent),
+  MouseWheel(SDL_MouseWheelEvent),
+  JoyAxis(SDL_JoyAxisEvent),
+  JoyBall(SDL_JoyBallEvent),
+  JoyHat(SDL_JoyHatEvent),
+  JoyButton(SDL_JoyButtonEvent),
+  JoyDevice(SDL_JoyDeviceEvent),
+  ControllerAxis(SDL_ControllerAxisEvent),
+  ControllerButton(SDL_ControllerButtonEvent),
+  ControllerDevice(SDL_ControllerDeviceEvent),
+  ControllerTouchpad(SDL_ControllerTouchpadEvent),
+  ControllerSensor(SDL_ControllerSensorEvent),
+  TouchFinger(SDL_TouchFingerEvent),
+  DollarGesture(SDL_DollarGestureEvent),
+  MultiGesture(SDL_MultiGestureEvent),
+  /// **Note:** the `file` of an [`SDL_DROPFILE`] or [`SDL_DROPTEXT`] event
+  /// still needs to be freed.
+  Drop(SDL_DropEvent),
+  AudioDevice(SDL_AudioDeviceEvent),
+  Sensor(SDL_SensorEvent),
+  /// Any type from [`SDL_USEREVENT`] up to (but not including)
+  /// [`SDL_LASTEVENT`].
+  User(SDL_UserEvent),
+  /// A type that fermium doesn't know about (eg: from a newer SDL2).
+  Unknown(SDL_CommonEvent),
+}
+
 /// Used with [`SDL_PeepEvents`]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_eventaction(pub i32);
 
@@ -893,13 +1091,18 @@ pub const SDL_ADDEVENT: SDL_eventaction = SDL_eventaction(0);
 pub const SDL_PEEKEVENT: SDL_eventaction = SDL_eventaction(1);
 #[allow(missing_docs)]
 pub const SDL_GETEVENT: SDL_eventaction = SDL_eventaction(2);
+impl_names_for_tuple_newtype!(SDL_eventaction {
+  SDL_ADDEVENT,
+  SDL_PEEKEVENT,
+  SDL_GETEVENT,
+});
 
 /// Event filter operation.
 pub type SDL_EventFilter = Option<
   unsafe extern "C" fn(userdata: *mut c_void, event: *mut SDL_Event) -> c_int,
 >;
 
-extern "C" {
+sdl_extern! {
   /// Pumps the event loop, gathering events from the input devices.
   ///
   /// This function updates the event queue and internal input device state.
@@ -1056,3 +1259,372 @@ pub const SDL_ENABLE: i32 = 1;
 pub unsafe fn SDL_GetEventState(type_: SDL_EventType) -> Uint8 {
   SDL_EventState(type_, SDL_QUERY)
 }
+
+/// An iterator over the events in the queue, see [`poll_iter`].
+#[derive(Debug)]
+pub struct PollIter {
+  _priv: (),
+}
+impl Iterator for PollIter {
+  type Item = Event;
+  #[inline]
+  fn next(&mut self) -> Option<Event> {
+    let mut event = SDL_Event::default();
+    if unsafe { SDL_PollEvent(&mut event) } == 1 {
+      Some(event.decode())
+    } else {
+      None
+    }
+  }
+}
+
+/// Iterates the events in the queue, using [`SDL_PollEvent`].
+///
+/// The iterator ends once the queue is empty, it doesn't wait for more events.
+///
+/// ## Safety
+/// * The events subsystem must be initialized, and this must be used on the
+///   thread that set the video mode (as with [`SDL_PollEvent`]).
+#[inline]
+#[must_use]
+pub unsafe fn poll_iter() -> PollIter {
+  PollIter { _priv: () }
+}
+
+/// Waits up to `timeout` milliseconds for an event, using
+/// [`SDL_WaitEventTimeout`].
+///
+/// **Returns:** the next event, or `None` if the timeout passed first (or
+/// there was an error).
+///
+/// ## Safety
+/// * As [`poll_iter`].
+#[inline]
+pub unsafe fn wait_timeout(timeout: c_int) -> Option<Event> {
+  let mut event = SDL_Event::default();
+  if SDL_WaitEventTimeout(&mut event, timeout) == 1 {
+    Some(event.decode())
+  } else {
+    None
+  }
+}
+
+/// A registered user event type which carries a boxed `T` as its data.
+///
+/// [`push`](Self::push) boxes the value and puts the pointer in `data1` of an
+/// [`SDL_UserEvent`], and [`take`](Self::take) turns it back into a `T` when
+/// the event comes out of the queue. Any events still queued when you're done
+/// should be cleared with [`flush`](Self::flush), which drops their values
+/// (a plain [`SDL_FlushEvents`] would leak them).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub struct UserEventType<T> {
+  type_: SDL_EventType,
+  _marker: core::marker::PhantomData<fn(T) -> T>,
+}
+#[cfg(feature = "alloc")]
+impl<T> Clone for UserEventType<T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+#[cfg(feature = "alloc")]
+impl<T> Copy for UserEventType<T> {}
+#[cfg(feature = "alloc")]
+impl<T> core::fmt::Debug for UserEventType<T> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_tuple("UserEventType").field(&self.type_.0).finish()
+  }
+}
+#[cfg(feature = "alloc")]
+impl<T: Send + 'static> UserEventType<T> {
+  /// Reserves a new event type with [`SDL_RegisterEvents`].
+  ///
+  /// **Returns:** the new type, or `None` if there are no user event types
+  /// left.
+  ///
+  /// ## Safety
+  /// * The events subsystem must be initialized.
+  #[inline]
+  pub unsafe fn register() -> Option<Self> {
+    let type_ = SDL_RegisterEvents(1);
+    if type_ == u32::MAX {
+      None
+    } else {
+      Some(Self {
+        type_: SDL_EventType(type_ as i32),
+        _marker: core::marker::PhantomData,
+      })
+    }
+  }
+
+  /// The event type that was reserved.
+  #[inline]
+  #[must_use]
+  pub const fn event_type(self) -> SDL_EventType {
+    self.type_
+  }
+
+  /// Pushes an event carrying `data` onto the queue with [`SDL_PushEvent`].
+  ///
+  /// This can be called from any thread.
+  ///
+  /// **Returns:** `Err(data)` if the event was dropped by the event filter or
+  /// there was an error (see [`SDL_GetErrorMsg`]).
+  ///
+  /// ## Safety
+  /// * The events subsystem must be initialized.
+  pub unsafe fn push(self, data: T) -> Result<(), T> {
+    let mut event = SDL_Event::default();
+    event.user.type_ = self.type_;
+    event.user.data1 =
+      alloc::boxed::Box::into_raw(alloc::boxed::Box::new(data)).cast();
+    if SDL_PushEvent(&mut event) == 1 {
+      Ok(())
+    } else {
+      Err(*alloc::boxed::Box::from_raw(event.user.data1.cast::<T>()))
+    }
+  }
+
+  /// Takes the data out of an event of this type.
+  ///
+  /// The data pointer of the event is set to null, so taking from the same
+  /// event again gives `None`.
+  ///
+  /// **Returns:** the data, or `None` if the event is some other type or the
+  /// data was already taken.
+  ///
+  /// ## Safety
+  /// * The event must have come from the queue (it can't be a copy of an event
+  ///   that the data was already taken from).
+  #[inline]
+  pub unsafe fn take(self, event: &mut SDL_Event) -> Option<T> {
+    self.take_user(&mut event.user)
+  }
+
+  /// As [`take`](Self::take), but for an already decoded [`Event::User`].
+  ///
+  /// ## Safety
+  /// * As [`take`](Self::take).
+  pub unsafe fn take_user(self, user: &mut SDL_UserEvent) -> Option<T> {
+    if user.type_ != self.type_ || user.data1.is_null() {
+      return None;
+    }
+    let data = core::mem::replace(&mut user.data1, core::ptr::null_mut());
+    Some(*alloc::boxed::Box::from_raw(data.cast::<T>()))
+  }
+
+  /// Removes all events of this type from the queue, dropping their data.
+  ///
+  /// **Returns:** the number of events removed.
+  ///
+  /// ## Safety
+  /// * The events subsystem must be initialized.
+  pub unsafe fn flush(self) -> usize {
+    let mut count = 0;
+    let mut events = [SDL_Event::default(); 16];
+    loop {
+      let got = SDL_PeepEvents(
+        events.as_mut_ptr(),
+        events.len() as c_int,
+        SDL_GETEVENT,
+        self.type_,
+        self.type_,
+      );
+      if got <= 0 {
+        break;
+      }
+      for event in &mut events[..got as usize] {
+        drop(self.take(event));
+      }
+      count += got as usize;
+    }
+    SDL_FlushEvents(self.type_, self.type_);
+    count
+  }
+}
+
+#[cfg(feature = "alloc")]
+type EventWatchFn = dyn FnMut(&SDL_Event) + Send;
+#[cfg(feature = "alloc")]
+type EventFilterFn = dyn FnMut(&mut SDL_Event) -> bool + Send;
+
+/// A closure along with a flag that's set while it's running.
+///
+/// SDL's event watcher lock is recursive, so when a watcher or filter pushes
+/// an event SDL calls it again on the same thread, and a second `&mut` to the
+/// closure would alias the first. Those nested calls are skipped instead.
+#[cfg(feature = "alloc")]
+struct NonReentrant<F: ?Sized> {
+  running: core::sync::atomic::AtomicBool,
+  f: core::cell::UnsafeCell<F>,
+}
+#[cfg(feature = "alloc")]
+impl<F> NonReentrant<F> {
+  fn new(f: F) -> Self {
+    Self {
+      running: core::sync::atomic::AtomicBool::new(false),
+      f: core::cell::UnsafeCell::new(f),
+    }
+  }
+}
+#[cfg(feature = "alloc")]
+impl<F: ?Sized> NonReentrant<F> {
+  /// Calls `call` with the closure, or gives `None` if it's already running.
+  ///
+  /// ## Safety
+  /// * Calls on different threads must not overlap.
+  unsafe fn call<R>(&self, call: impl FnOnce(&mut F) -> R) -> Option<R> {
+    use core::sync::atomic::Ordering;
+    if self.running.swap(true, Ordering::Acquire) {
+      return None;
+    }
+    let r = crate::no_unwind(|| call(&mut *self.f.get()));
+    self.running.store(false, Ordering::Release);
+    Some(r)
+  }
+}
+
+/// An event watcher added with [`add_event_watch`].
+///
+/// Dropping this removes the watcher with [`SDL_DelEventWatch`] and then frees
+/// the closure.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub struct EventWatch {
+  watcher: *mut alloc::boxed::Box<NonReentrant<EventWatchFn>>,
+}
+#[cfg(feature = "alloc")]
+impl Drop for EventWatch {
+  fn drop(&mut self) {
+    unsafe {
+      SDL_DelEventWatch(Some(event_watch_trampoline), self.watcher.cast());
+      drop(alloc::boxed::Box::from_raw(self.watcher));
+    }
+  }
+}
+// Safety: the closure is `Send`. `SDL_DelEventWatch` takes the event watcher
+// lock, which SDL holds while calling watchers, so when the guard is dropped on
+// another thread the closure is freed only after any call to it has finished.
+#[cfg(feature = "alloc")]
+unsafe impl Send for EventWatch {}
+
+/// Adds a closure which is called with each event as it's added to the queue,
+/// using [`SDL_AddEventWatch`].
+///
+/// The closure is called on whichever thread pushes the event, so it must be
+/// `Send`. If it panics the process is aborted, since the panic can't unwind
+/// into SDL. If it pushes an event itself, it isn't called for that event.
+///
+/// ## Safety
+/// * The events subsystem must be initialized.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use = "the watcher is removed when the EventWatch is dropped"]
+pub unsafe fn add_event_watch(
+  f: impl FnMut(&SDL_Event) + Send + 'static,
+) -> EventWatch {
+  let boxed: alloc::boxed::Box<NonReentrant<EventWatchFn>> =
+    alloc::boxed::Box::new(NonReentrant::new(f));
+  let watcher = alloc::boxed::Box::into_raw(alloc::boxed::Box::new(boxed));
+  SDL_AddEventWatch(Some(event_watch_trampoline), watcher.cast());
+  EventWatch { watcher }
+}
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn event_watch_trampoline(
+  userdata: *mut c_void, event: *mut SDL_Event,
+) -> c_int {
+  let f = &*userdata.cast::<alloc::boxed::Box<NonReentrant<EventWatchFn>>>();
+  let event = &*event;
+  // Safety: SDL holds the event watcher lock during the call.
+  f.call(|f| f(event));
+  0
+}
+
+/// An event filter set with [`set_event_filter`].
+///
+/// Dropping this clears the event filter (if it's still this one) and then
+/// frees the closure.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub struct EventFilter {
+  filter: *mut alloc::boxed::Box<NonReentrant<EventFilterFn>>,
+}
+#[cfg(feature = "alloc")]
+impl Drop for EventFilter {
+  fn drop(&mut self) {
+    unsafe {
+      let mut filter: SDL_EventFilter = None;
+      let mut userdata = core::ptr::null_mut();
+      if SDL_GetEventFilter(&mut filter, &mut userdata).into()
+        && userdata == self.filter.cast()
+      {
+        SDL_SetEventFilter(None, core::ptr::null_mut());
+      }
+      drop(alloc::boxed::Box::from_raw(self.filter));
+    }
+  }
+}
+// Safety: the closure is `Send`. SDL calls the filter while holding the event
+// watcher lock, and `SDL_SetEventFilter` takes that lock too, so when the guard
+// is dropped on another thread the filter is cleared only after any call to it
+// has finished.
+#[cfg(feature = "alloc")]
+unsafe impl Send for EventFilter {}
+
+/// Sets a closure as the event filter, using [`SDL_SetEventFilter`].
+///
+/// Each event is passed to the closure before it's added to the queue, and
+/// the event is dropped if the closure returns `false`. This replaces any
+/// existing event filter.
+///
+/// The closure is called on whichever thread pushes the event, so it must be
+/// `Send`. If it panics the process is aborted, since the panic can't unwind
+/// into SDL. If it pushes an event itself, it isn't called for that event and
+/// the event is kept.
+///
+/// ## Safety
+/// * The events subsystem must be initialized.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use = "the filter is cleared when the EventFilter is dropped"]
+pub unsafe fn set_event_filter(
+  f: impl FnMut(&mut SDL_Event) -> bool + Send + 'static,
+) -> EventFilter {
+  let boxed: alloc::boxed::Box<NonReentrant<EventFilterFn>> =
+    alloc::boxed::Box::new(NonReentrant::new(f));
+  let filter = alloc::boxed::Box::into_raw(alloc::boxed::Box::new(boxed));
+  SDL_SetEventFilter(Some(event_filter_trampoline), filter.cast());
+  EventFilter { filter }
+}
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn event_filter_trampoline(
+  userdata: *mut c_void, event: *mut SDL_Event,
+) -> c_int {
+  let f = &*userdata.cast::<alloc::boxed::Box<NonReentrant<EventFilterFn>>>();
+  let event = &mut *event;
+  // Safety: SDL holds the event watcher lock during the call.
+  f.call(|f| f(event)).unwrap_or(true) as c_int
+}
+
+/// Removes the events in the queue for which the closure returns `false`,
+/// using [`SDL_FilterEvents`].
+///
+/// The closure is only called during this function, on this thread. If it
+/// panics the process is aborted, since the panic can't unwind into SDL.
+///
+/// ## Safety
+/// * The events subsystem must be initialized.
+pub unsafe fn filter_events<F: FnMut(&mut SDL_Event) -> bool>(mut f: F) {
+  unsafe extern "C" fn trampoline<F: FnMut(&mut SDL_Event) -> bool>(
+    userdata: *mut c_void, event: *mut SDL_Event,
+  ) -> c_int {
+    let f = &mut *userdata.cast::<F>();
+    let event = &mut *event;
+    crate::no_unwind(|| f(event)) as c_int
+  }
+  SDL_FilterEvents(Some(trampoline::<F>), (&mut f as *mut F).cast());
+}
@@ -6,7 +6,7 @@ use crate::c_char;
 #[allow(unused)]
 use crate::stdinc::*;
 
-extern "C" {
+sdl_extern! {
   /// Get the path where the application resides.
   ///
   /// This is the directory where the application was run from, which is
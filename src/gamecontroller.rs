@@ -36,7 +36,7 @@ pub struct SDL_GameController(c_void);
 /// A game controller's type, sorted by console.
 ///
 /// See `SDL_CONTROLLER_TYPE_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GameControllerType(pub i32);
 #[allow(missing_docs)]
@@ -69,12 +69,24 @@ pub const SDL_CONTROLLER_TYPE_AMAZON_LUNA: SDL_GameControllerType =
 #[allow(missing_docs)]
 pub const SDL_CONTROLLER_TYPE_GOOGLE_STADIA: SDL_GameControllerType =
   SDL_GameControllerType(9);
+impl_names_for_tuple_newtype!(SDL_GameControllerType {
+  SDL_CONTROLLER_TYPE_UNKNOWN,
+  SDL_CONTROLLER_TYPE_XBOX360,
+  SDL_CONTROLLER_TYPE_XBOXONE,
+  SDL_CONTROLLER_TYPE_PS3,
+  SDL_CONTROLLER_TYPE_PS4,
+  SDL_CONTROLLER_TYPE_NINTENDO_SWITCH_PRO,
+  SDL_CONTROLLER_TYPE_VIRTUAL,
+  SDL_CONTROLLER_TYPE_PS5,
+  SDL_CONTROLLER_TYPE_AMAZON_LUNA,
+  SDL_CONTROLLER_TYPE_GOOGLE_STADIA,
+});
 
 /// The type of a binding between the underlying joystick and its controller
 /// abstraction.
 ///
 /// See `SDL_CONTROLLER_BINDTYPE_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GameControllerBindType(pub i32);
 #[allow(missing_docs)]
@@ -89,6 +101,12 @@ pub const SDL_CONTROLLER_BINDTYPE_AXIS: SDL_GameControllerBindType =
 #[allow(missing_docs)]
 pub const SDL_CONTROLLER_BINDTYPE_HAT: SDL_GameControllerBindType =
   SDL_GameControllerBindType(3);
+impl_names_for_tuple_newtype!(SDL_GameControllerBindType {
+  SDL_CONTROLLER_BINDTYPE_NONE,
+  SDL_CONTROLLER_BINDTYPE_BUTTON,
+  SDL_CONTROLLER_BINDTYPE_AXIS,
+  SDL_CONTROLLER_BINDTYPE_HAT,
+});
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -124,7 +142,7 @@ pub struct SDL_GameControllerButtonBind {
 /// varies between controllers.
 ///
 /// Trigger axis values range from 0 to [`SDL_JOYSTICK_AXIS_MAX`].
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GameControllerAxis(pub i32);
 
@@ -151,11 +169,20 @@ pub const SDL_CONTROLLER_AXIS_TRIGGERLEFT: SDL_GameControllerAxis =
 /// Right trigger
 pub const SDL_CONTROLLER_AXIS_TRIGGERRIGHT: SDL_GameControllerAxis =
   SDL_GameControllerAxis(5);
+impl_names_for_tuple_newtype!(SDL_GameControllerAxis {
+  SDL_CONTROLLER_AXIS_INVALID,
+  SDL_CONTROLLER_AXIS_LEFTX,
+  SDL_CONTROLLER_AXIS_LEFTY,
+  SDL_CONTROLLER_AXIS_RIGHTX,
+  SDL_CONTROLLER_AXIS_RIGHTY,
+  SDL_CONTROLLER_AXIS_TRIGGERLEFT,
+  SDL_CONTROLLER_AXIS_TRIGGERRIGHT,
+});
 /// The number of valid axis possibilities.
 pub const SDL_CONTROLLER_AXIS_MAX: usize = 6;
 
 /// The list of buttons available from a controller.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GameControllerButton(pub i32);
 
@@ -228,6 +255,30 @@ pub const SDL_CONTROLLER_BUTTON_PADDLE4: SDL_GameControllerButton =
 /// PS4/PS5 touchpad button.
 pub const SDL_CONTROLLER_BUTTON_TOUCHPAD: SDL_GameControllerButton =
   SDL_GameControllerButton(20);
+impl_names_for_tuple_newtype!(SDL_GameControllerButton {
+  SDL_CONTROLLER_BUTTON_INVALID,
+  SDL_CONTROLLER_BUTTON_A,
+  SDL_CONTROLLER_BUTTON_B,
+  SDL_CONTROLLER_BUTTON_X,
+  SDL_CONTROLLER_BUTTON_Y,
+  SDL_CONTROLLER_BUTTON_BACK,
+  SDL_CONTROLLER_BUTTON_GUIDE,
+  SDL_CONTROLLER_BUTTON_START,
+  SDL_CONTROLLER_BUTTON_LEFTSTICK,
+  SDL_CONTROLLER_BUTTON_RIGHTSTICK,
+  SDL_CONTROLLER_BUTTON_LEFTSHOULDER,
+  SDL_CONTROLLER_BUTTON_RIGHTSHOULDER,
+  SDL_CONTROLLER_BUTTON_DPAD_UP,
+  SDL_CONTROLLER_BUTTON_DPAD_DOWN,
+  SDL_CONTROLLER_BUTTON_DPAD_LEFT,
+  SDL_CONTROLLER_BUTTON_DPAD_RIGHT,
+  SDL_CONTROLLER_BUTTON_MISC1,
+  SDL_CONTROLLER_BUTTON_PADDLE1,
+  SDL_CONTROLLER_BUTTON_PADDLE2,
+  SDL_CONTROLLER_BUTTON_PADDLE3,
+  SDL_CONTROLLER_BUTTON_PADDLE4,
+  SDL_CONTROLLER_BUTTON_TOUCHPAD,
+});
 /// The number of valid controller button possibilities.
 pub const SDL_CONTROLLER_BUTTON_MAX: usize = 21;
 
@@ -245,7 +296,7 @@ pub unsafe fn SDL_GameControllerAddMappingsFromFile(
   )
 }
 
-extern "C" {
+sdl_extern! {
   /// Load a set of mappings from a seekable SDL data stream (memory or file).
   ///
   /// This is filtered by the current [`SDL_GetPlatform`].
@@ -624,6 +675,8 @@ extern "C" {
   /// * `size` The size of the data to send to the controller
   /// * **Returns:** 0, or -1 if this controller or driver doesn't support
   ///   effect packets
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_GameControllerSendEffect(
     gamecontroller: *mut SDL_GameController, data: *const c_void, size: c_int,
   ) -> c_int;
@@ -634,6 +687,8 @@ extern "C" {
   /// * `gamecontroller` The controller to query
   /// * `type` The type of sensor to query
   /// * **Returns:** the data rate, or 0.0f if the data rate is not available.
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_GameControllerGetSensorDataRate(
     gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
   ) -> c_float;
@@ -7,7 +7,7 @@ use crate::{c_int, rwops::*, stdinc::*, touch::*};
 #[repr(transparent)]
 pub struct SDL_GestureID(pub Sint64);
 
-extern "C" {
+sdl_extern! {
   /// Begin Recording a gesture on the specified touch, or all touches (-1).
   pub fn SDL_RecordGesture(touchId: SDL_TouchID) -> c_int;
 
@@ -80,7 +80,7 @@ pub const SDL_HINT_RENDER_DRIVER: &[u8] = c_str!("SDL_RENDER_DRIVER");
 ///
 /// By default shaders are used if OpenGL supports them.
 pub const SDL_HINT_RENDER_OPENGL_SHADERS: &[u8] =
-  c_str!("SDL_HINT_RENDER_OPENGL_SHADERS");
+  c_str!("SDL_RENDER_OPENGL_SHADERS");
 
 /// A variable controlling whether the Direct3D device is initialized for
 /// thread-safe operations.
@@ -1465,10 +1465,903 @@ pub const SDL_HINT_AUDIO_INCLUDE_MONITORS: &[u8] =
 pub const SDL_HINT_AUDIO_DEVICE_STREAM_ROLE: &[u8] =
   c_str!("SDL_AUDIO_DEVICE_STREAM_ROLE");
 
+/// Specify the behavior of Alt+Tab while the keyboard is grabbed.
+///
+/// By default, SDL emulates Alt+Tab functionality while the keyboard is grabbed
+/// and your window is full-screen. This prevents the user from getting stuck in
+/// your application if you've enabled keyboard grab.
+///
+/// The variable can be set to the following values:
+/// * "0": SDL will not handle Alt+Tab. Your application is responsible for
+///   handling Alt+Tab while the keyboard is grabbed.
+/// * "1": SDL will minimize your window when Alt+Tab is pressed (default)
+pub const SDL_HINT_ALLOW_ALT_TAB_WHILE_GRABBED: &[u8] =
+  c_str!("SDL_ALLOW_ALT_TAB_WHILE_GRABBED");
+
+/// Specify an application name.
+///
+/// This hint lets you specify the application name sent to the OS when
+/// required. For example, this will often appear in volume control applets for
+/// audio streams, and in lists of applications which are inhibiting the
+/// screensaver. You should use a string that describes your program ("My Game
+/// 2: The Revenge")
+///
+/// Setting this to "" or leaving it unset will have SDL use a reasonable
+/// default: probably the application's name or "SDL Application" if SDL doesn't
+/// have any better information.
+///
+/// Note that, for audio streams, this can be overridden with
+/// SDL_HINT_AUDIO_DEVICE_APP_NAME.
+///
+/// On targets where this is not supported, this hint does nothing.
+pub const SDL_HINT_APP_NAME: &[u8] = c_str!("SDL_APP_NAME");
+
+/// A variable that decides what audio backend to use.
+///
+/// By default, SDL will try all available audio backends in a reasonable order
+/// until it finds one that can work, but this hint allows the app or user to
+/// force a specific target, such as "alsa" if, say, you are on PulseAudio but
+/// want to try talking to the lower level instead.
+///
+/// This functionality has existed since SDL 2.0.0 (indeed, before that) but
+/// before 2.0.22 this was an environment variable only. In 2.0.22, it was
+/// upgraded to a full SDL hint, so you can set the environment variable as
+/// usual or programatically set the hint with SDL_SetHint, which won't
+/// propagate to child processes.
+///
+/// The default value is unset, in which case SDL will try to figure out the
+/// best audio backend on your behalf. This hint needs to be set before
+/// SDL_Init() is called to be useful.
+///
+/// This hint is available since SDL 2.0.22. Before then, you could set the
+/// environment variable to get the same effect.
+pub const SDL_HINT_AUDIODRIVER: &[u8] = c_str!("SDL_AUDIODRIVER");
+
+/// A variable that lets you disable the detection and use of DirectInput
+/// gamepad devices
+///
+/// The variable can be set to the following values:
+/// * "0": Disable DirectInput detection (only uses XInput)
+/// * "1": Enable DirectInput detection (the default)
+pub const SDL_HINT_DIRECTINPUT_ENABLED: &[u8] =
+  c_str!("SDL_DIRECTINPUT_ENABLED");
+
+/// A variable controlling whether raising the window should be done more
+/// forcefully
+///
+/// This variable can be set to the following values:
+/// * "0": No forcing (the default)
+/// * "1": Extra level of forcing
+///
+/// At present, this is only an issue under MS Windows, which makes it nearly
+/// impossible to programmatically move a window to the foreground, for
+/// "security" reasons. See <http://stackoverflow.com/a/34414846> for a
+/// discussion.
+pub const SDL_HINT_FORCE_RAISEWINDOW: &[u8] =
+  c_str!("SDL_HINT_FORCE_RAISEWINDOW");
+
+/// A variable containing a list of devices to ignore in SDL_hid_enumerate()
+///
+/// For example, to ignore the Shanwan DS3 controller and any Valve controller,
+/// you might have the string "0x2563/0x0523,0x28de/0x0000"
+pub const SDL_HINT_HIDAPI_IGNORE_DEVICES: &[u8] =
+  c_str!("SDL_HIDAPI_IGNORE_DEVICES");
+
+/// A variable to control whether certain IMEs should show native UI components
+/// (such as the Candidate List) instead of suppressing them.
+///
+/// The variable can be set to the following values:
+/// * "0": Native UI components are not display. (default)
+/// * "1": Native UI components are displayed.
+pub const SDL_HINT_IME_SHOW_UI: &[u8] = c_str!("SDL_IME_SHOW_UI");
+
+/// A variable to control if extended IME text support is enabled. If enabled
+/// then SDL_TextEditingExtEvent will be issued if the text would be truncated
+/// otherwise. Additionally SDL_TextInputEvent will be dispatched multiple times
+/// so that it is not truncated.
+///
+/// The variable can be set to the following values:
+/// * "0": Legacy behavior. Text can be truncated, no heap allocations.
+///   (default)
+/// * "1": Modern behavior.
+pub const SDL_HINT_IME_SUPPORT_EXTENDED_TEXT: &[u8] =
+  c_str!("SDL_IME_SUPPORT_EXTENDED_TEXT");
+
+/// A comma separated list of devices to open as joysticks
+///
+/// This variable is currently only used by the Linux joystick driver.
+pub const SDL_HINT_JOYSTICK_DEVICE: &[u8] = c_str!("SDL_JOYSTICK_DEVICE");
+
+/// A variable controlling whether "low_frequency_rumble" and
+/// "high_frequency_rumble" is used to implement the GameCube controller's 3
+/// rumble modes, Stop(0), Rumble(1), and StopHard(2) this is useful for
+/// applications that need full compatibility for things like ADSR envelopes.
+/// Stop is implemented by setting "low_frequency_rumble" to "0" and
+/// "high_frequency_rumble" ">0" Rumble is both at any arbitrary value, StopHard
+/// is implemented by setting both "low_frequency_rumble" and
+/// "high_frequency_rumble" to "0"
+///
+/// This variable can be set to the following values:
+/// * "0": Normal rumble behavior is behavior is used (default)
+/// * "1": Proper GameCube controller rumble behavior is used
+pub const SDL_HINT_JOYSTICK_GAMECUBE_RUMBLE_BRAKE: &[u8] =
+  c_str!("SDL_JOYSTICK_GAMECUBE_RUMBLE_BRAKE");
+
+/// A variable controlling whether Nintendo Switch Joy-Con controllers will be
+/// combined into a single Pro-like controller when using the HIDAPI driver
+///
+/// This variable can be set to the following values:
+/// * "0": Left and right Joy-Con controllers will not be combined and each will
+///   be a mini-gamepad
+/// * "1": Left and right Joy-Con controllers will be combined into a single
+///   controller (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_COMBINE_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_COMBINE_JOY_CONS");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// a Nintendo Switch Joy-Con controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED.
+pub const SDL_HINT_JOYSTICK_HIDAPI_JOYCON_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_JOYCON_HOME_LED");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Switch
+/// Joy-Cons should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+pub const SDL_HINT_JOYSTICK_HIDAPI_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_JOY_CONS");
+
+/// A variable controlling whether the HIDAPI driver for Amazon Luna controllers
+/// connected via Bluetooth should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+pub const SDL_HINT_JOYSTICK_HIDAPI_LUNA: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_LUNA");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Online classic
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+pub const SDL_HINT_JOYSTICK_HIDAPI_NINTENDO_CLASSIC: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_NINTENDO_CLASSIC");
+
+/// A variable controlling whether the HIDAPI driver for PS3 controllers should
+/// be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI on macOS, and "0" on
+/// other platforms.
+///
+/// It is not possible to use this driver on Windows, due to limitations in the
+/// default drivers installed. See <https://github.com/ViGEm/DsHidMini> for an
+/// alternative driver on Windows.
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS3: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS3");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a PS5 controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS5_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS5_PLAYER_LED");
+
+/// A variable controlling whether extended input reports should be used for PS5
+/// controllers when using the HIDAPI driver.
+///
+/// This variable can be set to the following values:
+/// * "0": extended reports are not enabled (the default)
+/// * "1": extended reports
+///
+/// Extended input reports allow rumble on Bluetooth PS5 controllers, but break
+/// DirectInput handling for applications that don't use SDL.
+///
+/// Once extended reports are enabled, they can not be disabled without power
+/// cycling the controller.
+///
+/// For compatibility with applications written for versions of SDL prior to the
+/// introduction of PS5 controller support, this value defaults to the value of
+/// SDL_HINT_JOYSTICK_HIDAPI_PS4_RUMBLE.
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS5_RUMBLE: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS5_RUMBLE");
+
+/// A variable controlling whether the HIDAPI driver for NVIDIA SHIELD
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+pub const SDL_HINT_JOYSTICK_HIDAPI_SHIELD: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SHIELD");
+
+/// A variable controlling whether the HIDAPI driver for Google Stadia
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+pub const SDL_HINT_JOYSTICK_HIDAPI_STADIA: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_STADIA");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// a Nintendo Switch Pro controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED.
+pub const SDL_HINT_JOYSTICK_HIDAPI_SWITCH_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SWITCH_HOME_LED");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a Nintendo Switch controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_SWITCH_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SWITCH_PLAYER_LED");
+
+/// A variable controlling whether Nintendo Switch Joy-Con controllers will be
+/// in vertical mode when using the HIDAPI driver
+///
+/// This variable can be set to the following values:
+/// * "0": Left and right Joy-Con controllers will not be in vertical mode (the
+///   default)
+/// * "1": Left and right Joy-Con controllers will be in vertical mode
+///
+/// This hint must be set before calling SDL_Init(SDL_INIT_GAMECONTROLLER)
+pub const SDL_HINT_JOYSTICK_HIDAPI_VERTICAL_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_VERTICAL_JOY_CONS");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Wii and Wii U
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// This driver doesn't work with the dolphinbar, so the default is SDL_FALSE
+/// for now.
+pub const SDL_HINT_JOYSTICK_HIDAPI_WII: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_WII");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a Wii controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_WII_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_WII_PLAYER_LED");
+
+/// A variable controlling whether the HIDAPI driver for XBox 360 controllers
+/// should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with an Xbox 360 controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED");
+
+/// A variable controlling whether the HIDAPI driver for XBox 360 wireless
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX_360
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_WIRELESS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360_WIRELESS");
+
+/// A variable controlling whether the HIDAPI driver for XBox One controllers
+/// should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_ONE");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// an Xbox One controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED. The default brightness is 0.4.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED");
+
+/// A variable controlling whether the RAWINPUT driver should pull correlated
+/// data from XInput.
+///
+/// This variable can be set to the following values:
+/// * "0": RAWINPUT driver will only use data from raw input APIs
+/// * "1": RAWINPUT driver will also pull data from XInput, providing better
+///   trigger axes, guide button presses, and rumble support for Xbox
+///   controllers
+///
+/// The default is "1". This hint applies to any joysticks opened after setting
+/// the hint.
+pub const SDL_HINT_JOYSTICK_RAWINPUT_CORRELATE_XINPUT: &[u8] =
+  c_str!("SDL_JOYSTICK_RAWINPUT_CORRELATE_XINPUT");
+
+/// A variable controlling whether the ROG Chakram mice should show up as
+/// joysticks
+///
+/// This variable can be set to the following values:
+/// * "0": ROG Chakram mice do not show up as joysticks (the default)
+/// * "1": ROG Chakram mice show up as joysticks
+pub const SDL_HINT_JOYSTICK_ROG_CHAKRAM: &[u8] =
+  c_str!("SDL_JOYSTICK_ROG_CHAKRAM");
+
+/// A variable that decides what KMSDRM device to use.
+///
+/// Internally, SDL might open something like "/dev/dri/cardNN" to access KMSDRM
+/// functionality, where "NN" is a device index number.
+///
+/// SDL makes a guess at the best index to use (usually zero), but the app or
+/// user can set this hint to a number between 0 and 99 to force selection.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_KMSDRM_DEVICE_INDEX: &[u8] =
+  c_str!("SDL_KMSDRM_DEVICE_INDEX");
+
+/// Determines whether SDL enforces that DRM master is required in order to
+/// initialize the KMSDRM video backend.
+///
+/// The DRM subsystem has a concept of a "DRM master" which is a DRM client that
+/// has the ability to set planes, set cursor, etc. When SDL is DRM master, it
+/// can draw to the screen using the SDL rendering APIs. Without DRM master, SDL
+/// is still able to process input and query attributes of attached displays,
+/// but it cannot change display state or draw to the screen directly.
+///
+/// In some cases, it can be useful to have the KMSDRM backend even if it cannot
+/// be used for rendering. An app may want to use SDL for input processing while
+/// using another rendering API (such as an MMAL overlay on Raspberry Pi) or
+/// using its own code to render to DRM overlays that SDL doesn't support.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// This variable can be set to the following values:
+/// * "0": SDL will allow usage of the KMSDRM backend without DRM master
+/// * "1": SDL Will require DRM master to use the KMSDRM backend (default)
+pub const SDL_HINT_KMSDRM_REQUIRE_DRM_MASTER: &[u8] =
+  c_str!("SDL_KMSDRM_REQUIRE_DRM_MASTER");
+
+/// A variable controlling whether joysticks on Linux will always treat 'hat'
+/// axis inputs (ABS_HAT0X - ABS_HAT3Y) as 8-way digital hats without checking
+/// whether they may be analog.
+///
+/// This variable can be set to the following values:
+/// * "0": Only map hat axis inputs to digital hat outputs if the input axes
+///   appear to actually be digital (the default)
+/// * "1": Always handle the input axes numbered ABS_HAT0X to ABS_HAT3Y as
+///   digital hats
+pub const SDL_HINT_LINUX_DIGITAL_HATS: &[u8] = c_str!("SDL_LINUX_DIGITAL_HATS");
+
+/// A variable controlling whether digital hats on Linux will apply deadzones to
+/// their underlying input axes or use unfiltered values.
+///
+/// This variable can be set to the following values:
+/// * "0": Return digital hat values based on unfiltered input axis values
+/// * "1": Return digital hat values with deadzones on the input axes taken into
+///   account (the default)
+pub const SDL_HINT_LINUX_HAT_DEADZONES: &[u8] =
+  c_str!("SDL_LINUX_HAT_DEADZONES");
+
+/// A variable controlling whether to use the classic /dev/input/js* joystick
+/// interface or the newer /dev/input/event* joystick interface on Linux
+///
+/// This variable can be set to the following values:
+/// * "0": Use /dev/input/event*
+/// * "1": Use /dev/input/js*
+///
+/// By default the /dev/input/event* interfaces are used
+pub const SDL_HINT_LINUX_JOYSTICK_CLASSIC: &[u8] =
+  c_str!("SDL_LINUX_JOYSTICK_CLASSIC");
+
+/// A variable controlling whether dispatching OpenGL context updates should
+/// block the dispatching thread until the main thread finishes processing
+///
+/// This variable can be set to the following values:
+/// * "0": Dispatching OpenGL context updates will block the dispatching thread
+///   until the main thread finishes processing (default).
+/// * "1": Dispatching OpenGL context updates will allow the dispatching thread
+///   to continue execution.
+///
+/// Generally you want the default, but if you have OpenGL code in a background
+/// thread on a Mac, and the main thread hangs because it's waiting for that
+/// background thread, but that background thread is also hanging because it's
+/// waiting for the main thread to do an update, this might fix your issue.
+///
+/// This hint only applies to macOS.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_MAC_OPENGL_ASYNC_DISPATCH: &[u8] =
+  c_str!("SDL_MAC_OPENGL_ASYNC_DISPATCH");
+
+/// A variable controlling whether the mouse is captured while mouse buttons are
+/// pressed
+///
+/// This variable can be set to the following values:
+/// * "0": The mouse is not captured while mouse buttons are pressed
+/// * "1": The mouse is captured while mouse buttons are pressed
+///
+/// By default the mouse is captured while mouse buttons are pressed so if the
+/// mouse is dragged outside the window, the application continues to receive
+/// mouse events until the button is released.
+pub const SDL_HINT_MOUSE_AUTO_CAPTURE: &[u8] = c_str!("SDL_MOUSE_AUTO_CAPTURE");
+
+/// A variable controlling whether relative mouse mode constrains the mouse to
+/// the center of the window
+///
+/// This variable can be set to the following values:
+/// * "0": Relative mouse mode constrains the mouse to the window
+/// * "1": Relative mouse mode constrains the mouse to the center of the window
+///
+/// Constraining to the center of the window works better for FPS games and when
+/// the application is running over RDP. Constraining to the whole window works
+/// better for 2D games and increases the chance that the mouse will be in the
+/// correct position when using high DPI mice.
+///
+/// By default SDL will constrain the mouse to the center of the window
+pub const SDL_HINT_MOUSE_RELATIVE_MODE_CENTER: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_MODE_CENTER");
+
+/// A variable controlling whether the system mouse acceleration curve is used
+/// for relative mouse motion.
+///
+/// This variable can be set to the following values:
+/// * "0": Relative mouse motion will be unscaled (the default)
+/// * "1": Relative mouse motion will be scaled using the system mouse
+///   acceleration curve.
+///
+/// If SDL_HINT_MOUSE_RELATIVE_SPEED_SCALE is set, that will override the system
+/// speed scale.
+pub const SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_SYSTEM_SCALE");
+
+/// A variable controlling whether a motion event should be generated for mouse
+/// warping in relative mode.
+///
+/// This variable can be set to the following values:
+/// * "0": Warping the mouse will not generate a motion event in relative mode
+/// * "1": Warping the mouse will generate a motion event in relative mode
+///
+/// By default warping the mouse will not generate motion events in relative
+/// mode. This avoids the application having to filter out large relative motion
+/// due to warping.
+pub const SDL_HINT_MOUSE_RELATIVE_WARP_MOTION: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_WARP_MOTION");
+
+/// A variable controlling the use of a sentinel event when polling the event
+/// queue
+///
+/// This variable can be set to the following values:
+/// * "0": Disable poll sentinels
+/// * "1": Enable poll sentinels
+///
+/// When polling for events, SDL_PumpEvents is used to gather new events from
+/// devices. If a device keeps producing new events between calls to
+/// SDL_PumpEvents, a poll loop will become stuck until the new events stop.
+/// This is most noticeable when moving a high frequency mouse.
+///
+/// By default, poll sentinels are enabled.
+pub const SDL_HINT_POLL_SENTINEL: &[u8] = c_str!("SDL_POLL_SENTINEL");
+
+/// A variable controlling if VSYNC is automatically disable if doesn't reach
+/// the enough FPS
+///
+/// This variable can be set to the following values:
+/// * "0": It will be using VSYNC as defined in the main flag. Default
+/// * "1": If VSYNC was previously enabled, then it will disable VSYNC if
+///   doesn't reach enough speed
+///
+/// By default SDL does not enable the automatic VSYNC
+pub const SDL_HINT_PS2_DYNAMIC_VSYNC: &[u8] = c_str!("SDL_PS2_DYNAMIC_VSYNC");
+
+/// A variable that decides whether to send SDL_QUIT when closing the final
+/// window.
+///
+/// By default, SDL sends an SDL_QUIT event when there is only one window and it
+/// receives an SDL_WINDOWEVENT_CLOSE event, under the assumption most apps
+/// would also take the loss of this window as a signal to terminate the
+/// program.
+///
+/// However, it's not unreasonable in some cases to have the program continue to
+/// live on, perhaps to create new windows later.
+///
+/// Changing this hint to "0" will cause SDL to not send an SDL_QUIT event when
+/// the final window is requesting to close. Note that in this case, there are
+/// still other legitimate reasons one might get an SDL_QUIT event: choosing
+/// "Quit" from the macOS menu bar, sending a SIGINT (ctrl-c) on Unix, etc.
+///
+/// The default value is "1". This hint can be changed at any time.
+///
+/// This hint is available since SDL 2.0.22. Before then, you always get an
+/// SDL_QUIT event when closing the final window.
+pub const SDL_HINT_QUIT_ON_LAST_WINDOW_CLOSE: &[u8] =
+  c_str!("SDL_QUIT_ON_LAST_WINDOW_CLOSE");
+
+/// A variable controlling how the 2D render API renders lines
+///
+/// This variable can be set to the following values:
+/// * "0": Use the default line drawing method (Bresenham's line algorithm as of
+///   SDL 2.0.20)
+/// * "1": Use the driver point API using Bresenham's line algorithm (correct,
+///   draws many points)
+/// * "2": Use the driver line API (occasionally misses line endpoints based on
+///   hardware driver quirks, was the default before 2.0.20)
+/// * "3": Use the driver geometry API (correct, draws thicker diagonal lines)
+///
+/// This variable should be set when the renderer is created.
+pub const SDL_HINT_RENDER_LINE_METHOD: &[u8] = c_str!("SDL_RENDER_LINE_METHOD");
+
+/// A variable controlling the scaling policy for SDL_RenderSetLogicalSize.
+///
+/// This variable can be set to the following values:
+/// * "0" or "letterbox": Uses letterbox/sidebars to fit the entire rendering on
+///   screen
+/// * "1" or "overscan": Will zoom the rendering so it fills the entire screen,
+///   allowing edges to be drawn offscreen
+///
+/// By default letterbox is used
+pub const SDL_HINT_RENDER_LOGICAL_SIZE_MODE: &[u8] =
+  c_str!("SDL_RENDER_LOGICAL_SIZE_MODE");
+
+/// Specify an "activity name" for screensaver inhibition.
+///
+/// Some platforms, notably Linux desktops, list the applications which are
+/// inhibiting the screensaver or other power-saving features.
+///
+/// This hint lets you specify the "activity name" sent to the OS when
+/// SDL_DisableScreenSaver() is used (or the screensaver is automatically
+/// disabled). The contents of this hint are used when the screensaver is
+/// disabled. You should use a string that describes what your program is doing
+/// (and, therefore, why the screensaver is disabled). For example, "Playing a
+/// game" or "Watching a video".
+///
+/// Setting this to "" or leaving it unset will have SDL use a reasonable
+/// default: "Playing a game" or something similar.
+///
+/// On targets where this is not supported, this hint does nothing.
+pub const SDL_HINT_SCREENSAVER_INHIBIT_ACTIVITY_NAME: &[u8] =
+  c_str!("SDL_SCREENSAVER_INHIBIT_ACTIVITY_NAME");
+
+/// A variable that treats trackpads as touch devices.
+///
+/// On macOS (and possibly other platforms in the future), SDL will report
+/// touches on a trackpad as mouse input, which is generally what users expect
+/// from this device; however, these are often actually full multitouch-capable
+/// touch devices, so it might be preferable to some apps to treat them as such.
+///
+/// Setting this hint to true will make the trackpad input report as a
+/// multitouch device instead of a mouse. The default is false.
+///
+/// Note that most platforms don't support this hint. As of 2.24.0, it only
+/// supports MacBooks' trackpads on macOS. Others may follow later.
+///
+/// This hint is checked during SDL_Init and can not be changed after.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_TRACKPAD_IS_TOUCH_ONLY: &[u8] =
+  c_str!("SDL_TRACKPAD_IS_TOUCH_ONLY");
+
+/// A variable that decides what video backend to use.
+///
+/// By default, SDL will try all available video backends in a reasonable order
+/// until it finds one that can work, but this hint allows the app or user to
+/// force a specific target, such as "x11" if, say, you are on Wayland but want
+/// to try talking to the X server instead.
+///
+/// This functionality has existed since SDL 2.0.0 (indeed, before that) but
+/// before 2.0.22 this was an environment variable only. In 2.0.22, it was
+/// upgraded to a full SDL hint, so you can set the environment variable as
+/// usual or programatically set the hint with SDL_SetHint, which won't
+/// propagate to child processes.
+///
+/// The default value is unset, in which case SDL will try to figure out the
+/// best video backend on your behalf. This hint needs to be set before
+/// SDL_Init() is called to be useful.
+///
+/// This hint is available since SDL 2.0.22. Before then, you could set the
+/// environment variable to get the same effect.
+pub const SDL_HINT_VIDEODRIVER: &[u8] = c_str!("SDL_VIDEODRIVER");
+
+/// A variable controlling whether the EGL window is allowed to be composited as
+/// transparent, rather than opaque.
+///
+/// Most window systems will always render windows opaque, even if the surface
+/// format has an alpha channel. This is not always true, however, so by default
+/// SDL will try to enforce opaque composition. To override this behavior, you
+/// can set this hint to "1".
+pub const SDL_HINT_VIDEO_EGL_ALLOW_TRANSPARENCY: &[u8] =
+  c_str!("SDL_VIDEO_EGL_ALLOW_TRANSPARENCY");
+
+/// When calling SDL_CreateWindowFrom(), make the window compatible with OpenGL.
+///
+/// This variable can be set to the following values:
+/// * "0": Don't add any graphics flags to the SDL_WindowFlags
+/// * "1": Add SDL_WINDOW_OPENGL to the SDL_WindowFlags
+///
+/// By default SDL will not make the foreign window compatible with OpenGL.
+pub const SDL_HINT_VIDEO_FOREIGN_WINDOW_OPENGL: &[u8] =
+  c_str!("SDL_VIDEO_FOREIGN_WINDOW_OPENGL");
+
+/// When calling SDL_CreateWindowFrom(), make the window compatible with Vulkan.
+///
+/// This variable can be set to the following values:
+/// * "0": Don't add any graphics flags to the SDL_WindowFlags
+/// * "1": Add SDL_WINDOW_VULKAN to the SDL_WindowFlags
+///
+/// By default SDL will not make the foreign window compatible with Vulkan.
+pub const SDL_HINT_VIDEO_FOREIGN_WINDOW_VULKAN: &[u8] =
+  c_str!("SDL_VIDEO_FOREIGN_WINDOW_VULKAN");
+
+/// A variable controlling whether the libdecor Wayland backend is allowed to be
+/// used.
+///
+/// This variable can be set to the following values:
+/// * "0": libdecor use is disabled.
+/// * "1": libdecor use is enabled (default).
+///
+/// libdecor is used over xdg-shell when xdg-decoration protocol is unavailable.
+pub const SDL_HINT_VIDEO_WAYLAND_ALLOW_LIBDECOR: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_ALLOW_LIBDECOR");
+
+/// Enable or disable mouse pointer warp emulation, needed by some older games.
+///
+/// When this hint is set, any SDL will emulate mouse warps using relative mouse
+/// mode. This is required for some older games (such as Source engine games),
+/// which warp the mouse to the centre of the screen rather than using relative
+/// mouse motion. Note that relative mouse mode may have different mouse
+/// acceleration behaviour than pointer warps.
+///
+/// This variable can be set to the following values:
+/// * "0": All mouse warps fail, as mouse warping is not available under
+///   wayland.
+/// * "1": Some mouse warps will be emulated by forcing relative mouse mode.
+///
+/// If not set, this is automatically enabled unless an application uses
+/// relative mouse mode directly.
+pub const SDL_HINT_VIDEO_WAYLAND_EMULATE_MOUSE_WARP: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_EMULATE_MOUSE_WARP");
+
+/// A variable controlling whether video mode emulation is enabled under
+/// Wayland.
+///
+/// When this hint is set, a standard set of emulated CVT video modes will be
+/// exposed for use by the application. If it is disabled, the only modes
+/// exposed will be the logical desktop size and, in the case of a scaled
+/// desktop, the native display resolution.
+///
+/// This variable can be set to the following values:
+/// * "0": Video mode emulation is disabled.
+/// * "1": Video mode emulation is enabled.
+///
+/// By default video mode emulation is enabled.
+pub const SDL_HINT_VIDEO_WAYLAND_MODE_EMULATION: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_MODE_EMULATION");
+
+/// A variable controlling whether the libdecor Wayland backend is preferred
+/// over native decrations.
+///
+/// When this hint is set, libdecor will be used to provide window decorations,
+/// even if xdg-decoration is available. (Note that, by default, libdecor will
+/// use xdg-decoration itself if available).
+///
+/// This variable can be set to the following values:
+/// * "0": libdecor is enabled only if server-side decorations are unavailable.
+/// * "1": libdecor is always enabled if available.
+///
+/// libdecor is used over xdg-shell when xdg-decoration protocol is unavailable.
+pub const SDL_HINT_VIDEO_WAYLAND_PREFER_LIBDECOR: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_PREFER_LIBDECOR");
+
+/// A variable controlling which touchpad should generate synthetic mouse events
+///
+/// This variable can be set to the following values:
+/// * "0": Only front touchpad should generate mouse events. Default
+/// * "1": Only back touchpad should generate mouse events.
+/// * "2": Both touchpads should generate mouse events.
+///
+/// By default SDL will generate mouse events for all touch devices
+pub const SDL_HINT_VITA_TOUCH_MOUSE_DEVICE: &[u8] =
+  c_str!("SDL_HINT_VITA_TOUCH_MOUSE_DEVICE");
+
+/// Controls whether SDL will declare the process to be DPI aware.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// The main purpose of declaring DPI awareness is to disable OS bitmap scaling
+/// of SDL windows on monitors with a DPI scale factor.
+///
+/// This hint is equivalent to requesting DPI awareness via external means (e.g.
+/// calling SetProcessDpiAwarenessContext) and does not cause SDL to use a
+/// virtualized coordinate system, so it will generally give you 1 SDL
+/// coordinate = 1 pixel even on high-DPI displays.
+///
+/// For more information, see:
+/// <https://docs.microsoft.com/en-us/windows/win32/hidpi/high-dpi-desktop-application-development-on-windows>
+///
+/// This variable can be set to the following values:
+/// * "": Do not change the DPI awareness (default).
+/// * "unaware": Declare the process as DPI unaware. (Windows 8.1 and later).
+/// * "system": Request system DPI awareness. (Vista and later).
+/// * "permonitor": Request per-monitor DPI awareness. (Windows 8.1 and later).
+/// * "permonitorv2": Request per-monitor V2 DPI awareness. (Windows 10, version
+///   1607 and later). The most visible difference from "permonitor" is that
+///   window title bar will be scaled to the visually correct size when dragging
+///   between monitors with different scale factors. This is the preferred DPI
+///   awareness level.
+///
+/// If the requested DPI awareness is not available on the currently running OS,
+/// SDL will try to request the best available match.
+pub const SDL_HINT_WINDOWS_DPI_AWARENESS: &[u8] =
+  c_str!("SDL_WINDOWS_DPI_AWARENESS");
+
+/// Uses DPI-scaled points as the SDL coordinate system on Windows.
+///
+/// This changes the SDL coordinate system units to be DPI-scaled points, rather
+/// than pixels everywhere. This means windows will be appropriately sized, even
+/// when created on high-DPI displays with scaling.
+///
+/// e.g. requesting a 640x480 window from SDL, on a display with 125% scaling in
+/// Windows display settings, will create a window with an 800x600 client area
+/// (in pixels).
+///
+/// Setting this to "1" implicitly requests process DPI awareness (setting
+/// SDL_WINDOWS_DPI_AWARENESS is unnecessary), and forces
+/// SDL_WINDOW_ALLOW_HIGHDPI on all windows.
+///
+/// This variable can be set to the following values:
+/// * "0": SDL coordinates equal Windows coordinates. No automatic window
+///   resizing when dragging between monitors with different scale factors
+///   (unless this is performed by Windows itself, which is the case when the
+///   process is DPI unaware).
+/// * "1": SDL coordinates are in DPI-scaled points. Automatically resize
+///   windows as needed on displays with non-100% scale factors.
+pub const SDL_HINT_WINDOWS_DPI_SCALING: &[u8] =
+  c_str!("SDL_WINDOWS_DPI_SCALING");
+
+/// Force SDL to use Critical Sections for mutexes on Windows. On Windows 7 and
+/// newer, Slim Reader/Writer Locks are available. They offer better
+/// performance, allocate no kernel ressources and use less memory. SDL will
+/// fall back to Critical Sections on older OS versions or if forced to by this
+/// hint.
+///
+/// This variable can be set to the following values:
+/// * "0": Use SRW Locks when available. If not, fall back to Critical Sections.
+///   (default)
+/// * "1": Force the use of Critical Sections in all cases.
+pub const SDL_HINT_WINDOWS_FORCE_MUTEX_CRITICAL_SECTIONS: &[u8] =
+  c_str!("SDL_WINDOWS_FORCE_MUTEX_CRITICAL_SECTIONS");
+
+/// Force SDL to use Kernel Semaphores on Windows. Kernel Semaphores are
+/// inter-process and require a context switch on every interaction. On Windows
+/// 8 and newer, the WaitOnAddress API is available. Using that and atomics to
+/// implement semaphores increases performance. SDL will fall back to Kernel
+/// Objects on older OS versions or if forced to by this hint.
+///
+/// This variable can be set to the following values:
+/// * "0": Use Atomics and WaitOnAddress API when available. If not, fall back
+///   to Kernel Objects. (default)
+/// * "1": Force the use of Kernel Objects in all cases.
+pub const SDL_HINT_WINDOWS_FORCE_SEMAPHORE_KERNEL: &[u8] =
+  c_str!("SDL_WINDOWS_FORCE_SEMAPHORE_KERNEL");
+
+/// Use the D3D9Ex API introduced in Windows Vista, instead of normal D3D9.
+/// Direct3D 9Ex contains changes to state management that can eliminate device
+/// loss errors during scenarios like Alt+Tab or UAC prompts. D3D9Ex may require
+/// some changes to your application to cope with the new behavior, so this is
+/// disabled by default.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// For more information on Direct3D 9Ex, see:
+/// * <https://docs.microsoft.com/en-us/windows/win32/direct3darticles/graphics-apis-in-windows-vista#direct3d-9ex>
+/// * <https://docs.microsoft.com/en-us/windows/win32/direct3darticles/direct3d-9ex-improvements>
+///
+/// This variable can be set to the following values:
+/// * "0": Use the original Direct3D 9 API (default)
+/// * "1": Use the Direct3D 9Ex API on Vista and later (and fall back if D3D9Ex
+///   is unavailable)
+pub const SDL_HINT_WINDOWS_USE_D3D9EX: &[u8] = c_str!("SDL_WINDOWS_USE_D3D9EX");
+
+/// A variable controlling whether the window is activated when the
+/// SDL_ShowWindow function is called
+///
+/// This variable can be set to the following values:
+/// * "0": The window is activated when the SDL_ShowWindow function is called
+/// * "1": The window is not activated when the SDL_ShowWindow function is
+///   called
+///
+/// By default SDL will activate the window when the SDL_ShowWindow function is
+/// called
+pub const SDL_HINT_WINDOW_NO_ACTIVATION_WHEN_SHOWN: &[u8] =
+  c_str!("SDL_WINDOW_NO_ACTIVATION_WHEN_SHOWN");
+
+/// Mark X11 windows as override-redirect.
+///
+/// If set, this _might_ increase framerate at the expense of the desktop not
+/// working as expected. Override-redirect windows aren't noticed by the window
+/// manager at all.
+///
+/// You should probably only use this for fullscreen windows, and you probably
+/// shouldn't even use it for that. But it's here if you want to try!
+pub const SDL_HINT_X11_FORCE_OVERRIDE_REDIRECT: &[u8] =
+  c_str!("SDL_X11_FORCE_OVERRIDE_REDIRECT");
+
+/// A variable that forces X11 windows to create as a custom type.
+///
+/// This is currently only used for X11 and ignored elsewhere.
+///
+/// During SDL_CreateWindow, SDL uses the _NET_WM_WINDOW_TYPE X11 property to
+/// report to the window manager the type of window it wants to create. This
+/// might be set to various things if SDL_WINDOW_TOOLTIP or
+/// SDL_WINDOW_POPUP_MENU, etc, were specified. For "normal" windows that
+/// haven't set a specific type, this hint can be used to specify a custom type.
+/// For example, a dock window might set this to "_NET_WM_WINDOW_TYPE_DOCK".
+///
+/// If not set or set to "", this hint is ignored. This hint must be set before
+/// the SDL_CreateWindow() call that it is intended to affect.
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_X11_WINDOW_TYPE: &[u8] = c_str!("SDL_X11_WINDOW_TYPE");
+
 /// An enumeration of hint priorities.
 ///
 /// See `SDL_HINT_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_HintPriority(pub i32);
 
@@ -1478,6 +2371,11 @@ pub const SDL_HINT_DEFAULT: SDL_HintPriority = SDL_HintPriority(0);
 pub const SDL_HINT_NORMAL: SDL_HintPriority = SDL_HintPriority(1);
 #[allow(missing_docs)]
 pub const SDL_HINT_OVERRIDE: SDL_HintPriority = SDL_HintPriority(2);
+impl_names_for_tuple_newtype!(SDL_HintPriority {
+  SDL_HINT_DEFAULT,
+  SDL_HINT_NORMAL,
+  SDL_HINT_OVERRIDE,
+});
 
 /// See [`SDL_AddHintCallback`] and [`SDL_DelHintCallback`].
 pub type SDL_HintCallback = Option<
@@ -1489,7 +2387,7 @@ pub type SDL_HintCallback = Option<
   ),
 >;
 
-extern "C" {
+sdl_extern! {
   /// Set a hint with a specific priority
   ///
   /// The priority controls the behavior when setting a hint that already has a
@@ -1536,6 +2434,26 @@ extern "C" {
     name: *const c_char, callback: SDL_HintCallback, userdata: *mut c_void,
   );
 
+  /// Reset a hint to the default value.
+  ///
+  /// This will reset a hint to the value of the environment variable, or NULL
+  /// if the environment isn't set. Callbacks will be called normally with this
+  /// change.
+  ///
+  /// **Returns:** `SDL_TRUE` if the hint was set, `SDL_FALSE` otherwise.
+  #[cfg(feature = "sdl_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_24")))]
+  pub fn SDL_ResetHint(name: *const c_char) -> SDL_bool;
+
+  /// Reset all hints to the default values.
+  ///
+  /// This will reset all hints to the value of the associated environment
+  /// variable, or NULL if the environment isn't set. Callbacks will be called
+  /// normally with this change.
+  #[cfg(feature = "sdl_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_26")))]
+  pub fn SDL_ResetHints();
+
   /// Clear all hints
   ///
   /// This function is called during [`SDL_Quit`] to free stored hints.
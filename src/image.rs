@@ -0,0 +1,97 @@
+//! Loading and saving images with the `SDL2_image` library.
+//!
+//! Requires the `image` feature, and `SDL2_image` must be installed.
+//!
+//! Errors from these functions are reported through [`SDL_GetErrorMsg`].
+
+use crate::{c_char, c_int, renderer::*, rwops::*, surface::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// Flags for [`IMG_Init`], which image formats to load the support for.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct IMG_InitFlags(pub c_int);
+impl_bit_ops_for_tuple_newtype!(IMG_InitFlags);
+#[allow(missing_docs)]
+pub const IMG_INIT_JPG: IMG_InitFlags = IMG_InitFlags(0x00000001);
+#[allow(missing_docs)]
+pub const IMG_INIT_PNG: IMG_InitFlags = IMG_InitFlags(0x00000002);
+#[allow(missing_docs)]
+pub const IMG_INIT_TIF: IMG_InitFlags = IMG_InitFlags(0x00000004);
+#[allow(missing_docs)]
+pub const IMG_INIT_WEBP: IMG_InitFlags = IMG_InitFlags(0x00000008);
+/// Added in `SDL2_image` 2.6
+pub const IMG_INIT_JXL: IMG_InitFlags = IMG_InitFlags(0x00000010);
+/// Added in `SDL2_image` 2.6
+pub const IMG_INIT_AVIF: IMG_InitFlags = IMG_InitFlags(0x00000020);
+impl_names_for_tuple_newtype!(flags IMG_InitFlags {
+  IMG_INIT_JPG,
+  IMG_INIT_PNG,
+  IMG_INIT_TIF,
+  IMG_INIT_WEBP,
+  IMG_INIT_JXL,
+  IMG_INIT_AVIF,
+});
+
+sdl_extern! {
+  /// Loads the support for the image formats given.
+  ///
+  /// Formats are also loaded automatically the first time an image of that
+  /// format is loaded, so this is only needed to check for support up front
+  /// (or to avoid the delay on first use).
+  ///
+  /// **Returns:** the flags of the formats that are now loaded. Check this
+  /// against the flags you asked for to see if any failed.
+  pub fn IMG_Init(flags: IMG_InitFlags) -> c_int;
+
+  /// Unloads the support for all image formats.
+  ///
+  /// Call this once at the end of the program, even if you never called
+  /// [`IMG_Init`].
+  pub fn IMG_Quit();
+
+  /// Loads an image file into a new surface.
+  ///
+  /// The format is picked by the file's content and extension.
+  ///
+  /// The new surface should be freed with [`SDL_FreeSurface`].
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn IMG_Load(file: *const c_char) -> *mut SDL_Surface;
+
+  /// Loads an image from a seekable SDL data stream into a new surface.
+  ///
+  /// If `freesrc` is non-zero, the stream will be closed after being read.
+  ///
+  /// The new surface should be freed with [`SDL_FreeSurface`].
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn IMG_Load_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut SDL_Surface;
+
+  /// Loads an image file directly into a new texture for the renderer.
+  ///
+  /// The new texture should be freed with [`SDL_DestroyTexture`].
+  ///
+  /// **Returns:** the new texture, or NULL if there was an error.
+  pub fn IMG_LoadTexture(
+    renderer: *mut SDL_Renderer, file: *const c_char,
+  ) -> *mut SDL_Texture;
+
+  /// Saves a surface as a PNG file.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn IMG_SavePNG(surface: *mut SDL_Surface, file: *const c_char) -> c_int;
+
+  /// Saves a surface as a JPEG file.
+  ///
+  /// * `quality` goes from 0 to 100, with higher being better quality (and a
+  ///   bigger file).
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn IMG_SaveJPG(
+    surface: *mut SDL_Surface, file: *const c_char, quality: c_int,
+  ) -> c_int;
+}
@@ -28,6 +28,8 @@ use crate::{c_char, c_int, c_void, stdinc::*};
 
 // makes rustdoc link properly!
 #[allow(unused)]
+use crate::error::*;
+#[allow(unused)]
 use crate::events::*;
 #[allow(unused)]
 use crate::hints::*;
@@ -63,7 +65,7 @@ pub struct SDL_JoystickID(pub i32);
 /// The general physical category of a joystick device.
 ///
 /// See `SDL_JOYSTICK_TYPE_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_JoystickType(pub i32);
 
@@ -90,11 +92,23 @@ pub const SDL_JOYSTICK_TYPE_DRUM_KIT: SDL_JoystickType = SDL_JoystickType(7);
 pub const SDL_JOYSTICK_TYPE_ARCADE_PAD: SDL_JoystickType = SDL_JoystickType(8);
 #[allow(missing_docs)]
 pub const SDL_JOYSTICK_TYPE_THROTTLE: SDL_JoystickType = SDL_JoystickType(9);
+impl_names_for_tuple_newtype!(SDL_JoystickType {
+  SDL_JOYSTICK_TYPE_UNKNOWN,
+  SDL_JOYSTICK_TYPE_GAMECONTROLLER,
+  SDL_JOYSTICK_TYPE_WHEEL,
+  SDL_JOYSTICK_TYPE_ARCADE_STICK,
+  SDL_JOYSTICK_TYPE_FLIGHT_STICK,
+  SDL_JOYSTICK_TYPE_DANCE_PAD,
+  SDL_JOYSTICK_TYPE_GUITAR,
+  SDL_JOYSTICK_TYPE_DRUM_KIT,
+  SDL_JOYSTICK_TYPE_ARCADE_PAD,
+  SDL_JOYSTICK_TYPE_THROTTLE,
+});
 
 /// The power level of a joystick.
 ///
 /// See `SDL_JOYSTICK_POWER_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_JoystickPowerLevel(pub i32);
 
@@ -119,6 +133,123 @@ pub const SDL_JOYSTICK_POWER_WIRED: SDL_JoystickPowerLevel =
 /// Honestly, this particular constant is probably pointless.
 pub const SDL_JOYSTICK_POWER_MAX: SDL_JoystickPowerLevel =
   SDL_JoystickPowerLevel(5);
+impl_names_for_tuple_newtype!(SDL_JoystickPowerLevel {
+  SDL_JOYSTICK_POWER_UNKNOWN,
+  SDL_JOYSTICK_POWER_EMPTY,
+  SDL_JOYSTICK_POWER_LOW,
+  SDL_JOYSTICK_POWER_MEDIUM,
+  SDL_JOYSTICK_POWER_FULL,
+  SDL_JOYSTICK_POWER_WIRED,
+  SDL_JOYSTICK_POWER_MAX,
+});
+
+/// The current version of the [`SDL_VirtualJoystickDesc`] structure.
+pub const SDL_VIRTUAL_JOYSTICK_DESC_VERSION: Uint16 = 1;
+
+/// The structure that defines an extended virtual joystick description.
+///
+/// The caller must zero the structure and then initialize the version with
+/// [`SDL_VIRTUAL_JOYSTICK_DESC_VERSION`] before passing it to
+/// [`SDL_JoystickAttachVirtualEx`]. All other elements of this structure are
+/// optional and can be left 0. The [`Default`] impl does exactly this for you.
+///
+/// See Also: [`VirtualJoystickBuilder`] for a version that handles the
+/// `userdata` for you.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_VirtualJoystickDesc {
+  /// [`SDL_VIRTUAL_JOYSTICK_DESC_VERSION`]
+  pub version: Uint16,
+  /// An [`SDL_JoystickType`] value.
+  pub type_: Uint16,
+  /// The number of axes on this joystick.
+  pub naxes: Uint16,
+  /// The number of buttons on this joystick.
+  pub nbuttons: Uint16,
+  /// The number of hats on this joystick.
+  pub nhats: Uint16,
+  /// The USB vendor ID of this joystick.
+  pub vendor_id: Uint16,
+  /// The USB product ID of this joystick.
+  pub product_id: Uint16,
+  /// unused
+  pub padding: Uint16,
+  /// A mask of which buttons are valid for this controller.
+  ///
+  /// eg: `1 << SDL_CONTROLLER_BUTTON_A.0`
+  pub button_mask: Uint32,
+  /// A mask of which axes are valid for this controller.
+  ///
+  /// eg: `1 << SDL_CONTROLLER_AXIS_LEFTX.0`
+  pub axis_mask: Uint32,
+  /// The name of the joystick (SDL copies this string).
+  pub name: *const c_char,
+  /// User data pointer passed to callbacks.
+  pub userdata: *mut c_void,
+  /// Called when the joystick state should be updated.
+  pub Update: Option<unsafe extern "C" fn(userdata: *mut c_void)>,
+  /// Called when the player index is set.
+  pub SetPlayerIndex:
+    Option<unsafe extern "C" fn(userdata: *mut c_void, player_index: c_int)>,
+  /// Implements [`SDL_JoystickRumble`].
+  pub Rumble: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      low_frequency_rumble: Uint16,
+      high_frequency_rumble: Uint16,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickRumbleTriggers`].
+  pub RumbleTriggers: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      left_rumble: Uint16,
+      right_rumble: Uint16,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickSetLED`].
+  pub SetLED: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      red: Uint8,
+      green: Uint8,
+      blue: Uint8,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickSendEffect`].
+  pub SendEffect: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      data: *const c_void,
+      size: c_int,
+    ) -> c_int,
+  >,
+}
+impl Default for SDL_VirtualJoystickDesc {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      version: SDL_VIRTUAL_JOYSTICK_DESC_VERSION,
+      type_: 0,
+      naxes: 0,
+      nbuttons: 0,
+      nhats: 0,
+      vendor_id: 0,
+      product_id: 0,
+      padding: 0,
+      button_mask: 0,
+      axis_mask: 0,
+      name: core::ptr::null(),
+      userdata: core::ptr::null_mut(),
+      Update: None,
+      SetPlayerIndex: None,
+      Rumble: None,
+      RumbleTriggers: None,
+      SetLED: None,
+      SendEffect: None,
+    }
+  }
+}
 
 /// Set max recognized G-force from accelerometer.
 pub const SDL_IPHONE_MAX_GFORCE: f32 = 5.0;
@@ -148,7 +279,7 @@ pub const SDL_HAT_LEFTUP: u8 = SDL_HAT_LEFT | SDL_HAT_UP;
 #[allow(missing_docs)]
 pub const SDL_HAT_LEFTDOWN: u8 = SDL_HAT_LEFT | SDL_HAT_DOWN;
 
-extern "C" {
+sdl_extern! {
   /// Locking for multi-threaded access to the joystick API
   ///
   /// If you are using the joystick API or handling events from multiple threads
@@ -242,6 +373,26 @@ extern "C" {
     type_: SDL_JoystickType, naxes: c_int, nbuttons: c_int, nhats: c_int,
   ) -> c_int;
 
+  /// Attach a new virtual joystick with extended properties.
+  ///
+  /// Returns the joystick's device index, or -1 if an error occurred.
+  ///
+  /// The `desc` is copied by SDL (including the `name` string), but the
+  /// `userdata` pointer must stay valid until the joystick is detached.
+  #[cfg(feature = "sdl_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_24")))]
+  pub fn SDL_JoystickAttachVirtualEx(
+    desc: *const SDL_VirtualJoystickDesc,
+  ) -> c_int;
+
+  /// Detach a virtual joystick.
+  ///
+  /// * `device_index` a value previously returned from
+  ///   [`SDL_JoystickAttachVirtual`] or [`SDL_JoystickAttachVirtualEx`].
+  ///
+  /// Returns 0 on success, or -1 if an error occurred.
+  pub fn SDL_JoystickDetachVirtual(device_index: c_int) -> c_int;
+
   /// Indicates whether or not a virtual-joystick is at a given device index.
   pub fn SDL_JoystickIsVirtual(device_index: c_int) -> SDL_bool;
 
@@ -464,6 +615,20 @@ extern "C" {
     joystick: *mut SDL_Joystick, red: Uint8, green: Uint8, blue: Uint8,
   ) -> c_int;
 
+  /// Send a joystick specific effect packet.
+  ///
+  /// * `joystick` The joystick to affect
+  /// * `data` The data to send to the joystick
+  /// * `size` The size of the data to send to the joystick
+  ///
+  /// **Returns:** 0, or -1 if this joystick or driver doesn't support effect
+  /// packets.
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
+  pub fn SDL_JoystickSendEffect(
+    joystick: *mut SDL_Joystick, data: *const c_void, size: c_int,
+  ) -> c_int;
+
   /// Close a joystick previously opened with [`SDL_JoystickOpen`].
   pub fn SDL_JoystickClose(joystick: *mut SDL_Joystick);
 
@@ -472,3 +637,315 @@ extern "C" {
     joystick: *mut SDL_Joystick,
   ) -> SDL_JoystickPowerLevel;
 }
+
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+type BoxedFn<F> = Option<alloc::boxed::Box<F>>;
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+type SendEffectFn = dyn FnMut(&[u8]) -> c_int + Send;
+
+/// The boxed callback state behind a [`VirtualJoystick`].
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+#[derive(Default)]
+struct VirtualJoystickCallbacks {
+  update: BoxedFn<dyn FnMut() + Send>,
+  set_player_index: BoxedFn<dyn FnMut(c_int) + Send>,
+  rumble: BoxedFn<dyn FnMut(Uint16, Uint16) -> c_int + Send>,
+  rumble_triggers: BoxedFn<dyn FnMut(Uint16, Uint16) -> c_int + Send>,
+  set_led: BoxedFn<dyn FnMut(Uint8, Uint8, Uint8) -> c_int + Send>,
+  send_effect: BoxedFn<SendEffectFn>,
+}
+
+/// Builds a [`SDL_VirtualJoystickDesc`] using Rust closures as the callbacks.
+///
+/// Only the callbacks you set are passed to SDL, so (for example)
+/// `SDL_JoystickHasRumble` will report `SDL_FALSE` unless you called
+/// [`on_rumble`](Self::on_rumble).
+///
+/// SDL calls the closures while holding the joystick lock, from whichever
+/// thread called the matching SDL function, so they must be `Send`. If a
+/// closure panics the process is aborted, since the panic can't unwind into C.
+///
+/// A bundled SDL2 built with the `bundled_no_hidapi` feature doesn't have the
+/// virtual joystick driver, so attaching will fail with it.
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "alloc", feature = "sdl_2_24"))))]
+pub struct VirtualJoystickBuilder<'a> {
+  desc: SDL_VirtualJoystickDesc,
+  name: Option<&'a core::ffi::CStr>,
+  callbacks: VirtualJoystickCallbacks,
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+impl<'a> VirtualJoystickBuilder<'a> {
+  /// Starts a new description with no controls and no callbacks.
+  #[inline]
+  #[must_use]
+  pub fn new(type_: SDL_JoystickType) -> Self {
+    let desc = SDL_VirtualJoystickDesc {
+      type_: type_.0 as Uint16,
+      ..SDL_VirtualJoystickDesc::default()
+    };
+    Self { desc, name: None, callbacks: VirtualJoystickCallbacks::default() }
+  }
+  /// The name of the joystick.
+  #[inline]
+  #[must_use]
+  pub fn name(mut self, name: &'a core::ffi::CStr) -> Self {
+    self.name = Some(name);
+    self
+  }
+  /// The number of axes on this joystick.
+  #[inline]
+  #[must_use]
+  pub fn axes(mut self, naxes: Uint16) -> Self {
+    self.desc.naxes = naxes;
+    self
+  }
+  /// The number of buttons on this joystick.
+  #[inline]
+  #[must_use]
+  pub fn buttons(mut self, nbuttons: Uint16) -> Self {
+    self.desc.nbuttons = nbuttons;
+    self
+  }
+  /// The number of hats on this joystick.
+  #[inline]
+  #[must_use]
+  pub fn hats(mut self, nhats: Uint16) -> Self {
+    self.desc.nhats = nhats;
+    self
+  }
+  /// The USB vendor and product IDs of this joystick.
+  #[inline]
+  #[must_use]
+  pub fn vendor_product(
+    mut self, vendor_id: Uint16, product_id: Uint16,
+  ) -> Self {
+    self.desc.vendor_id = vendor_id;
+    self.desc.product_id = product_id;
+    self
+  }
+  /// A mask of which buttons are valid for this controller.
+  ///
+  /// eg: `1 << SDL_CONTROLLER_BUTTON_A.0`
+  #[inline]
+  #[must_use]
+  pub fn button_mask(mut self, button_mask: Uint32) -> Self {
+    self.desc.button_mask = button_mask;
+    self
+  }
+  /// A mask of which axes are valid for this controller.
+  ///
+  /// eg: `1 << SDL_CONTROLLER_AXIS_LEFTX.0`
+  #[inline]
+  #[must_use]
+  pub fn axis_mask(mut self, axis_mask: Uint32) -> Self {
+    self.desc.axis_mask = axis_mask;
+    self
+  }
+  /// Called when the joystick state should be updated.
+  ///
+  /// This is the place to call [`SDL_JoystickSetVirtualAxis`] and friends.
+  #[inline]
+  #[must_use]
+  pub fn on_update(mut self, f: impl FnMut() + Send + 'static) -> Self {
+    self.callbacks.update = Some(alloc::boxed::Box::new(f));
+    self
+  }
+  /// Called when the player index is set.
+  #[inline]
+  #[must_use]
+  pub fn on_set_player_index(
+    mut self, f: impl FnMut(c_int) + Send + 'static,
+  ) -> Self {
+    self.callbacks.set_player_index = Some(alloc::boxed::Box::new(f));
+    self
+  }
+  /// Implements [`SDL_JoystickRumble`], return 0 on success or -1 on error.
+  #[inline]
+  #[must_use]
+  pub fn on_rumble(
+    mut self, f: impl FnMut(Uint16, Uint16) -> c_int + Send + 'static,
+  ) -> Self {
+    self.callbacks.rumble = Some(alloc::boxed::Box::new(f));
+    self
+  }
+  /// Implements [`SDL_JoystickRumbleTriggers`], return 0 on success or -1 on
+  /// error.
+  #[inline]
+  #[must_use]
+  pub fn on_rumble_triggers(
+    mut self, f: impl FnMut(Uint16, Uint16) -> c_int + Send + 'static,
+  ) -> Self {
+    self.callbacks.rumble_triggers = Some(alloc::boxed::Box::new(f));
+    self
+  }
+  /// Implements [`SDL_JoystickSetLED`], return 0 on success or -1 on error.
+  #[inline]
+  #[must_use]
+  pub fn on_set_led(
+    mut self, f: impl FnMut(Uint8, Uint8, Uint8) -> c_int + Send + 'static,
+  ) -> Self {
+    self.callbacks.set_led = Some(alloc::boxed::Box::new(f));
+    self
+  }
+  /// Implements [`SDL_JoystickSendEffect`], return 0 on success or -1 on
+  /// error.
+  #[inline]
+  #[must_use]
+  pub fn on_send_effect(
+    mut self, f: impl FnMut(&[u8]) -> c_int + Send + 'static,
+  ) -> Self {
+    self.callbacks.send_effect = Some(alloc::boxed::Box::new(f));
+    self
+  }
+
+  /// Attaches the virtual joystick with [`SDL_JoystickAttachVirtualEx`].
+  ///
+  /// On failure you get `None`, and [`SDL_GetErrorMsg`] has more info.
+  pub fn attach(self) -> Option<VirtualJoystick> {
+    let Self { mut desc, name, callbacks } = self;
+    desc.name = name.map_or(core::ptr::null(), |n| n.as_ptr());
+    if callbacks.update.is_some() {
+      desc.Update = Some(virtual_update);
+    }
+    if callbacks.set_player_index.is_some() {
+      desc.SetPlayerIndex = Some(virtual_set_player_index);
+    }
+    if callbacks.rumble.is_some() {
+      desc.Rumble = Some(virtual_rumble);
+    }
+    if callbacks.rumble_triggers.is_some() {
+      desc.RumbleTriggers = Some(virtual_rumble_triggers);
+    }
+    if callbacks.set_led.is_some() {
+      desc.SetLED = Some(virtual_set_led);
+    }
+    if callbacks.send_effect.is_some() {
+      desc.SendEffect = Some(virtual_send_effect);
+    }
+    let callbacks =
+      alloc::boxed::Box::into_raw(alloc::boxed::Box::new(callbacks));
+    desc.userdata = callbacks.cast();
+    let device_index = unsafe { SDL_JoystickAttachVirtualEx(&desc) };
+    if device_index < 0 {
+      drop(unsafe { alloc::boxed::Box::from_raw(callbacks) });
+      return None;
+    }
+    let instance_id = unsafe { SDL_JoystickGetDeviceInstanceID(device_index) };
+    Some(VirtualJoystick { instance_id, callbacks })
+  }
+}
+
+/// A virtual joystick attached with [`VirtualJoystickBuilder`].
+///
+/// Dropping this detaches the joystick with [`SDL_JoystickDetachVirtual`] and
+/// then frees the callback closures. If the joystick subsystem has already
+/// shut down (which detaches all virtual joysticks) the closures are just
+/// freed.
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "alloc", feature = "sdl_2_24"))))]
+pub struct VirtualJoystick {
+  instance_id: SDL_JoystickID,
+  callbacks: *mut VirtualJoystickCallbacks,
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+impl VirtualJoystick {
+  /// The instance ID of the virtual joystick.
+  #[inline]
+  #[must_use]
+  pub fn instance_id(&self) -> SDL_JoystickID {
+    self.instance_id
+  }
+  /// The current device index of the virtual joystick, if it's still
+  /// attached.
+  ///
+  /// Device indexes change as other devices are added and removed, so this
+  /// is looked up each time you call it.
+  #[must_use]
+  pub fn device_index(&self) -> Option<c_int> {
+    let count = unsafe { SDL_NumJoysticks() };
+    (0..count).find(|&device_index| unsafe {
+      SDL_JoystickGetDeviceInstanceID(device_index) == self.instance_id
+    })
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+impl Drop for VirtualJoystick {
+  fn drop(&mut self) {
+    if let Some(device_index) = self.device_index() {
+      unsafe { SDL_JoystickDetachVirtual(device_index) };
+    }
+    drop(unsafe { alloc::boxed::Box::from_raw(self.callbacks) });
+  }
+}
+// Safety: the callbacks are all `Send`, and SDL only touches them while the
+// joystick lock is held.
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe impl Send for VirtualJoystick {}
+
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_update(userdata: *mut c_void) {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  if let Some(f) = callbacks.update.as_mut() {
+    crate::no_unwind(f)
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_set_player_index(
+  userdata: *mut c_void, player_index: c_int,
+) {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  if let Some(f) = callbacks.set_player_index.as_mut() {
+    crate::no_unwind(|| f(player_index))
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_rumble(
+  userdata: *mut c_void, low_frequency_rumble: Uint16,
+  high_frequency_rumble: Uint16,
+) -> c_int {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  match callbacks.rumble.as_mut() {
+    Some(f) => {
+      crate::no_unwind(|| f(low_frequency_rumble, high_frequency_rumble))
+    }
+    None => -1,
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_rumble_triggers(
+  userdata: *mut c_void, left_rumble: Uint16, right_rumble: Uint16,
+) -> c_int {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  match callbacks.rumble_triggers.as_mut() {
+    Some(f) => crate::no_unwind(|| f(left_rumble, right_rumble)),
+    None => -1,
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_set_led(
+  userdata: *mut c_void, red: Uint8, green: Uint8, blue: Uint8,
+) -> c_int {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  match callbacks.set_led.as_mut() {
+    Some(f) => crate::no_unwind(|| f(red, green, blue)),
+    None => -1,
+  }
+}
+#[cfg(all(feature = "alloc", feature = "sdl_2_24"))]
+unsafe extern "C" fn virtual_send_effect(
+  userdata: *mut c_void, data: *const c_void, size: c_int,
+) -> c_int {
+  let callbacks = &mut *userdata.cast::<VirtualJoystickCallbacks>();
+  match callbacks.send_effect.as_mut() {
+    Some(f) => {
+      let bytes: &[u8] = if data.is_null() || size <= 0 {
+        &[]
+      } else {
+        core::slice::from_raw_parts(data.cast(), size as usize)
+      };
+      crate::no_unwind(|| f(bytes))
+    }
+    None => -1,
+  }
+}
@@ -22,7 +22,7 @@ pub struct SDL_Keysym {
   pub unused: Uint32,
 }
 
-extern "C" {
+sdl_extern! {
   /// Get the window which currently has keyboard focus.
   pub fn SDL_GetKeyboardFocus() -> *mut SDL_Window;
 
@@ -34,6 +34,15 @@ extern "C" {
   /// by using [`SDL_Scancode`] values.
   pub fn SDL_GetKeyboardState(numkeys: *mut c_int) -> *const u8;
 
+  /// Clear the state of the keyboard.
+  ///
+  /// This function will generate key up events for all pressed keys.
+  ///
+  /// See Also: [`SDL_GetKeyboardState`]
+  #[cfg(feature = "sdl_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_24")))]
+  pub fn SDL_ResetKeyboard();
+
   /// Get the current key modifier state for the keyboard.
   pub fn SDL_GetModState() -> SDL_Keymod;
 
@@ -104,6 +113,18 @@ extern "C" {
   /// See Also: [`SDL_StartTextInput`], [`SDL_HasScreenKeyboardSupport`]
   pub fn SDL_StopTextInput();
 
+  /// Dismiss the composition window/IME without disabling the subsystem.
+  ///
+  /// See Also: [`SDL_StartTextInput`], [`SDL_StopTextInput`]
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_ClearComposition();
+
+  /// Returns if an IME Composite or Candidate window is currently shown.
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_IsTextInputShown() -> SDL_bool;
+
   /// Set the rectangle used to type Unicode text inputs.
   ///
   /// This is used as a hint for IME and on-screen keyboard placement.
@@ -18,12 +18,81 @@
 //! If you use the `static_bundled_build` cargo feature then this will build
 //! SDL2 using a bundled copy of the source and then statically link to that.
 //! Building SDL2 can take a bit during that first build (usually 1 minute or
-//! more).
+//! more). Adding the `dynamic_link` feature builds the bundled SDL2 as a
+//! shared library instead, and dynamically links to that.
+//! The `SDL2_SOURCE_DIR` environment variable builds a different SDL2 source
+//! folder instead of the bundled one, and `FERMIUM_SDL_CACHE_DIR` keeps the
+//! build in a cache folder to reuse in later builds.
 //!
 //! # Crate Features
 //! * `cargo_check`: This causes the entire build.rs to exit early. This is
 //!   useful if you don't need to make an executable, such as `cargo check` or
 //!   `cargo doc`.
+//! * `sdl_2_0_16`, `sdl_2_0_18`, `sdl_2_0_20`, `sdl_2_0_22`, `sdl_2_24`,
+//!   `sdl_2_26`: By default only the functions available in SDL 2.0.14 are
+//!   declared, so that you can't accidentally depend on a newer SDL2 than your
+//!   users have installed. Each of these features declares the functions that
+//!   were added in that version of SDL2 (and enables the earlier version
+//!   features). The `static_bundled_build` feature enables all of them, since
+//!   it always uses the newest SDL2. If you'd rather check for a newer
+//!   function at runtime, see the [`optional`] module.
+//! * `dynamic_loading`: The crate won't link to SDL2 at all. Instead you call
+//!   `load` at runtime to open the library, and every function calls
+//!   through a table that `load` fills in. This lets a program start up
+//!   without SDL2 installed and give the user a helpful error. It can't be
+//!   combined with `static_bundled_build`.
+//! * `bundled_no_*`, `bundled_video_*`, `bundled_static_only`, `headless`:
+//!   These adjust what goes into the bundled SDL2 build (and enable
+//!   `static_bundled_build`). See the crate's `Cargo.toml` for the full list.
+//! * `test_harness`: Builds SDL's `SDL2_test` library along with the bundled
+//!   SDL2 (enabling `static_bundled_build`), and declares its functions in the
+//!   `test_harness` module.
+//! * `image`: Declares the `SDL2_image` functions in the `image` module, and
+//!   links to the system's `SDL2_image`.
+//! * `ttf`: Declares the `SDL2_ttf` functions in the `ttf` module, and links to
+//!   the system's `SDL2_ttf`.
+//! * `mixer`: Declares the `SDL2_mixer` functions in the `mixer` module, and
+//!   links to the system's `SDL2_mixer`.
+//! * `net`: Declares the `SDL2_net` functions in the `net` module, and links to
+//!   the system's `SDL2_net`.
+//! * `alloc`: Enables helpers that need the `alloc` crate, such as the
+//!   [`VirtualJoystickBuilder`](crate::joystick::VirtualJoystickBuilder) which
+//!   boxes up your callback closures, and the
+//!   [`UserEventType`](crate::events::UserEventType) which boxes up the data
+//!   of your user events. The `replay` module, for recording and replaying
+//!   event streams, also needs this.
+//!
+//! # Build Metadata
+//! The crate sets `links = "SDL2"`, so the build script of a crate that
+//! depends on fermium can read these environment variables (when they're
+//! known) about the SDL2 being used:
+//! * `DEP_SDL2_INCLUDE`: The folder that holds `SDL.h`, for building C code
+//!   against the same headers.
+//! * `DEP_SDL2_LIB_DIR`: The folder that holds the SDL2 library.
+//! * `DEP_SDL2_VERSION`: The SDL2 version, such as `2.26.5`. Compare this
+//!   yourself if your crate needs to know what SDL2 will be used.
+//! * `DEP_SDL2_STATIC`: `true` if SDL2 is linked statically, otherwise
+//!   `false`.
+//!
+//! When the version is known the build script also sets a `fermium_sdl_*` cfg
+//! (eg: `fermium_sdl_2_24`) on this crate for each `sdl_*` feature's version
+//! that the SDL2 is at least as new as.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(feature = "dynamic_loading", feature = "static_bundled_build"))]
+compile_error!(
+  "`dynamic_loading` can't be used with `static_bundled_build`, pick one."
+);
+
+#[cfg(all(
+  feature = "dynamic_loading",
+  any(feature = "image", feature = "ttf", feature = "mixer", feature = "net")
+))]
+compile_error!(
+  "`dynamic_loading` can't be used with the SDL2 extension library features."
+);
 
 pub use core::ffi::{
   c_char, c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short,
@@ -85,6 +154,184 @@ macro_rules! impl_bit_ops_for_tuple_newtype {
   };
 }
 
+// Gives a newtype a `name` method, looking up which of the listed constants it
+// equals, and a `Debug` impl that prints that name. With `flags` the `Debug`
+// impl instead prints each of the listed flags that are set, joined with `|`.
+// When constants share a value the first one listed is the name used.
+macro_rules! impl_names_for_tuple_newtype {
+  ($t:ty { $($name:ident),* $(,)? }) => {
+    impl_names_for_tuple_newtype!(@name $t { $($name),* });
+    impl core::fmt::Debug for $t {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+          Some(name) => f.write_str(name),
+          None => f.debug_tuple(stringify!($t)).field(&self.0).finish(),
+        }
+      }
+    }
+  };
+  (flags $t:ty { $($name:ident),* $(,)? }) => {
+    impl_names_for_tuple_newtype!(@name $t { $($name),* });
+    impl core::fmt::Debug for $t {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name() {
+          return f.write_str(name);
+        }
+        if self.0 == 0 {
+          return f.debug_tuple(stringify!($t)).field(&self.0).finish();
+        }
+        let mut rest = self.0;
+        let mut sep = "";
+        for (value, name) in [$(($name.0, stringify!($name))),*] {
+          if value != 0 && rest & value == value {
+            write!(f, "{sep}{name}")?;
+            rest &= !value;
+            sep = " | ";
+          }
+        }
+        if rest != 0 {
+          write!(f, "{sep}{rest:#X}")?;
+        }
+        Ok(())
+      }
+    }
+  };
+  (@name $t:ty { $($name:ident),* }) => {
+    impl $t {
+      /// The name of the constant with this value, if there is one.
+      #[inline]
+      #[must_use]
+      #[allow(unreachable_patterns)]
+      pub const fn name(self) -> Option<&'static str> {
+        match self {
+          $($name => Some(stringify!($name)),)*
+          _ => None,
+        }
+      }
+    }
+  };
+}
+
+/// The symbol a declaration links to: its `link_name` if it has one, otherwise
+/// its own name.
+#[cfg(feature = "dynamic_loading")]
+macro_rules! sdl_symbol {
+  ($name:ident) => {
+    stringify!($name)
+  };
+  ($name:ident, $link_name:literal) => {
+    $link_name
+  };
+}
+
+// Note: All of the SDL2 function declarations go through this macro instead of
+// being written as a plain `extern "C"` block. Normally it expands to exactly
+// that block, but with the `dynamic_loading` feature it instead makes a table
+// of function pointers for the module, which `load` fills in, and a wrapper
+// function for each declaration which calls through the table. The wrappers
+// are plain Rust functions rather than `extern "C"` ones, so that calling one
+// before `load` can panic without aborting the process.
+macro_rules! sdl_extern {
+  ($(
+    $(#[doc = $doc:literal])*
+    $(#[cfg($cfg:meta)])*
+    $(#[cfg_attr($($cfg_attr:tt)*)])*
+    $(#[link_name = $link_name:literal])?
+    pub fn $name:ident($($arg:ident : $t:ty),* $(,)?) $(-> $ret:ty)?;
+  )*) => {
+    #[cfg(not(feature = "dynamic_loading"))]
+    extern "C" {
+      $(
+        $(#[doc = $doc])*
+        $(#[cfg($cfg)])*
+        $(#[cfg_attr($($cfg_attr)*)])*
+        $(#[link_name = $link_name])?
+        pub fn $name($($arg: $t),*) $(-> $ret)?;
+      )*
+    }
+
+    #[cfg(feature = "dynamic_loading")]
+    struct FnTable {
+      $(
+        $(#[cfg($cfg)])*
+        $name: core::sync::atomic::AtomicPtr<core::ffi::c_void>,
+      )*
+    }
+
+    #[cfg(feature = "dynamic_loading")]
+    static FN_TABLE: FnTable = FnTable {
+      $(
+        $(#[cfg($cfg)])*
+        $name: core::sync::atomic::AtomicPtr::new(core::ptr::null_mut()),
+      )*
+    };
+
+    /// Fills in this module's function table using `resolve`, which gets
+    /// null-terminated symbol names.
+    ///
+    /// Every entry is written even if some are missing, and the first missing
+    /// symbol is returned as the error.
+    #[cfg(feature = "dynamic_loading")]
+    pub(crate) fn load_fn_table(
+      resolve: &mut dyn FnMut(&'static str) -> *mut core::ffi::c_void,
+    ) -> Result<(), &'static str> {
+      let mut missing = None;
+      $(
+        $(#[cfg($cfg)])*
+        {
+          let p = resolve(concat!(sdl_symbol!($name $(, $link_name)?), "\0"));
+          if p.is_null() && missing.is_none() {
+            missing = Some(sdl_symbol!($name $(, $link_name)?));
+          }
+          FN_TABLE.$name.store(p, core::sync::atomic::Ordering::Release);
+        }
+      )*
+      match missing {
+        None => Ok(()),
+        Some(name) => Err(name),
+      }
+    }
+
+    $(
+      #[cfg(feature = "dynamic_loading")]
+      $(#[doc = $doc])*
+      $(#[cfg($cfg)])*
+      $(#[cfg_attr($($cfg_attr)*)])*
+      #[inline]
+      #[allow(clippy::too_many_arguments)]
+      pub unsafe fn $name($($arg: $t),*) $(-> $ret)? {
+        let p = FN_TABLE.$name.load(core::sync::atomic::Ordering::Acquire);
+        if p.is_null() {
+          crate::dynamic_loading::not_loaded(stringify!($name));
+        }
+        let f: unsafe extern "C" fn($($t),*) $(-> $ret)? =
+          core::mem::transmute(p);
+        f($($arg),*)
+      }
+    )*
+  };
+}
+
+/// Aborts the process if it's dropped during a panic.
+struct AbortOnUnwind;
+impl Drop for AbortOnUnwind {
+  fn drop(&mut self) {
+    panic!("a callback panicked, aborting");
+  }
+}
+
+/// Calls `f`, aborting instead if it panics.
+///
+/// Rust closures that SDL calls back into go through this, since a panic
+/// can't be allowed to unwind out into C.
+#[inline]
+pub(crate) fn no_unwind<R>(f: impl FnOnce() -> R) -> R {
+  let bomb = AbortOnUnwind;
+  let r = f();
+  core::mem::forget(bomb);
+  r
+}
+
 // Note(Lokathor): Declarations are organized into modules according to SDL's
 // public header organization. A file like `include/SDL_foo.h` becomes a module
 // named `foo`. Also there is a `prelude` module which lets you grab all exports
@@ -92,6 +339,20 @@ macro_rules! impl_bit_ops_for_tuple_newtype {
 
 pub mod prelude;
 
+// Note: `optional` doesn't correspond to any SDL header, it holds runtime
+// lookups for the declarations which are gated behind the `sdl_*` features.
+pub mod optional;
+
+// Note: `replay` doesn't correspond to any SDL header either, it records and
+// replays event streams.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub mod replay;
+
+// Note: `synth` doesn't correspond to any SDL header either, it pushes
+// synthesized input events for tests.
+pub mod synth;
+
 // TODO: haptic (joystick force feedback system).
 // TODO: shape (allows shaped windows).
 // TODO: mutex (portable, no_std mutex would be handy).
@@ -133,11 +394,38 @@ pub mod video;
 #[cfg(feature = "vulkan")]
 pub mod vulkan;
 
+#[cfg(feature = "test_harness")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "test_harness")))]
+pub mod test_harness;
+
+#[cfg(feature = "image")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "image")))]
+pub mod image;
+
+#[cfg(feature = "ttf")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "ttf")))]
+pub mod ttf;
+
+#[cfg(feature = "mixer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "mixer")))]
+pub mod mixer;
+
+#[cfg(feature = "net")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "net")))]
+pub mod net;
+
+#[cfg(feature = "dynamic_loading")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "dynamic_loading")))]
+pub mod dynamic_loading;
+#[cfg(feature = "dynamic_loading")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "dynamic_loading")))]
+pub use dynamic_loading::{load, LoadError};
+
 /// SDL2's initialization flags.
 ///
 /// These are the flags which may be passed to [`SDL_Init`]. You should specify
 /// the subsystems which you will be using in your application.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_InitFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_InitFlags);
@@ -170,8 +458,20 @@ pub const SDL_INIT_EVERYTHING: SDL_InitFlags = SDL_InitFlags(
     | SDL_INIT_GAMECONTROLLER.0
     | SDL_INIT_SENSOR.0,
 );
+impl_names_for_tuple_newtype!(flags SDL_InitFlags {
+  SDL_INIT_TIMER,
+  SDL_INIT_AUDIO,
+  SDL_INIT_VIDEO,
+  SDL_INIT_JOYSTICK,
+  SDL_INIT_HAPTIC,
+  SDL_INIT_GAMECONTROLLER,
+  SDL_INIT_EVENTS,
+  SDL_INIT_SENSOR,
+  SDL_INIT_NOPARACHUTE,
+  SDL_INIT_EVERYTHING,
+});
 
-extern "C" {
+sdl_extern! {
   /// This function initializes the subsystems specified by `flags`.
   ///
   /// **Returns:** 0 on success or a negative error code on failure
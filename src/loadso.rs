@@ -2,7 +2,7 @@
 
 use crate::{c_char, c_void};
 
-extern "C" {
+sdl_extern! {
   /// This function dynamically loads a shared object and returns a pointer to
   /// the object handle (or NULL if there was an error).
   ///
@@ -11,7 +11,7 @@ use crate::*;
 /// If supported, will display warning icon, etc.
 ///
 /// See the `SDL_MESSAGEBOX_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_MessageBoxFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_MessageBoxFlags);
@@ -31,13 +31,20 @@ pub const SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT: SDL_MessageBoxFlags =
 /// buttons placed right to left
 pub const SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT: SDL_MessageBoxFlags =
   SDL_MessageBoxFlags(0x00000100);
+impl_names_for_tuple_newtype!(flags SDL_MessageBoxFlags {
+  SDL_MESSAGEBOX_ERROR,
+  SDL_MESSAGEBOX_WARNING,
+  SDL_MESSAGEBOX_INFORMATION,
+  SDL_MESSAGEBOX_BUTTONS_LEFT_TO_RIGHT,
+  SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT,
+});
 
 /// [`SDL_MessageBoxButtonData`] flags.
 ///
 /// If supported, will display warning icon, etc.
 ///
 /// See the `SDL_MESSAGEBOX_BUTTON_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_MessageBoxButtonFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_MessageBoxButtonFlags);
@@ -48,6 +55,10 @@ pub const SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT: SDL_MessageBoxButtonFlags =
 /// Marks the default button when escape is hit.
 pub const SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT: SDL_MessageBoxButtonFlags =
   SDL_MessageBoxButtonFlags(0x00000002);
+impl_names_for_tuple_newtype!(flags SDL_MessageBoxButtonFlags {
+  SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT,
+  SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT,
+});
 
 /// Individual button data.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -79,7 +90,7 @@ pub struct SDL_MessageBoxColor {
 }
 
 /// See the `SDL_MESSAGEBOX_COLOR_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_MessageBoxColorType(pub u32);
 
@@ -98,6 +109,13 @@ pub const SDL_MESSAGEBOX_COLOR_BUTTON_BACKGROUND: SDL_MessageBoxColorType =
 #[allow(missing_docs)]
 pub const SDL_MESSAGEBOX_COLOR_BUTTON_SELECTED: SDL_MessageBoxColorType =
   SDL_MessageBoxColorType(4);
+impl_names_for_tuple_newtype!(SDL_MessageBoxColorType {
+  SDL_MESSAGEBOX_COLOR_BACKGROUND,
+  SDL_MESSAGEBOX_COLOR_TEXT,
+  SDL_MESSAGEBOX_COLOR_BUTTON_BORDER,
+  SDL_MESSAGEBOX_COLOR_BUTTON_BACKGROUND,
+  SDL_MESSAGEBOX_COLOR_BUTTON_SELECTED,
+});
 #[allow(missing_docs)]
 pub const SDL_MESSAGEBOX_COLOR_MAX: usize = 5;
 
@@ -134,7 +152,7 @@ impl Default for SDL_MessageBoxData {
   }
 }
 
-extern "C" {
+sdl_extern! {
   /// Create a modal message box.
   ///
   /// * `messageboxdata` The [SDL_MessageBoxData`] structure with title, text,
@@ -0,0 +1,426 @@
+//! Playing sound effects and music, using the `SDL2_mixer` library.
+//!
+//! Requires the `mixer` feature, and `SDL2_mixer` (2.6 or later) must be
+//! installed.
+//!
+//! The mixer has a number of channels that each play one [`Mix_Chunk`] (a
+//! fully decoded sample) at a time, plus one separate music stream that's
+//! decoded as it plays. Errors from these functions are reported through
+//! [`SDL_GetErrorMsg`].
+
+use crate::{audio::*, c_char, c_double, c_int, c_void, rwops::*, stdinc::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// The default number of mixing channels (see [`Mix_AllocateChannels`]).
+pub const MIX_CHANNELS: c_int = 8;
+/// A good default frequency for [`Mix_OpenAudioDevice`].
+pub const MIX_DEFAULT_FREQUENCY: c_int = 44100;
+/// A good default format for [`Mix_OpenAudioDevice`].
+pub const MIX_DEFAULT_FORMAT: SDL_AudioFormat = AUDIO_S16SYS;
+/// A good default channel count (stereo) for [`Mix_OpenAudioDevice`].
+pub const MIX_DEFAULT_CHANNELS: c_int = 2;
+/// The maximum volume of a channel, chunk, or the music.
+pub const MIX_MAX_VOLUME: c_int = 128;
+/// The "channel" to use with [`Mix_RegisterEffect`] for an effect on the final
+/// mixed output.
+pub const MIX_CHANNEL_POST: c_int = -2;
+
+/// Flags for [`Mix_Init`], which decoders to load the support for.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct MIX_InitFlags(pub c_int);
+impl_bit_ops_for_tuple_newtype!(MIX_InitFlags);
+#[allow(missing_docs)]
+pub const MIX_INIT_FLAC: MIX_InitFlags = MIX_InitFlags(0x00000001);
+#[allow(missing_docs)]
+pub const MIX_INIT_MOD: MIX_InitFlags = MIX_InitFlags(0x00000002);
+#[allow(missing_docs)]
+pub const MIX_INIT_MP3: MIX_InitFlags = MIX_InitFlags(0x00000008);
+#[allow(missing_docs)]
+pub const MIX_INIT_OGG: MIX_InitFlags = MIX_InitFlags(0x00000010);
+#[allow(missing_docs)]
+pub const MIX_INIT_MID: MIX_InitFlags = MIX_InitFlags(0x00000020);
+#[allow(missing_docs)]
+pub const MIX_INIT_OPUS: MIX_InitFlags = MIX_InitFlags(0x00000040);
+impl_names_for_tuple_newtype!(flags MIX_InitFlags {
+  MIX_INIT_FLAC,
+  MIX_INIT_MOD,
+  MIX_INIT_MP3,
+  MIX_INIT_OGG,
+  MIX_INIT_MID,
+  MIX_INIT_OPUS,
+});
+
+/// A sound sample, fully decoded into the output format.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Mix_Chunk {
+  /// Non-zero if `abuf` is owned by the chunk.
+  pub allocated: c_int,
+  /// The sample data.
+  pub abuf: *mut Uint8,
+  /// The length of the sample data, in bytes.
+  pub alen: Uint32,
+  /// The volume of the chunk (0 to [`MIX_MAX_VOLUME`]).
+  pub volume: Uint8,
+}
+
+/// An opaque structure for music, which is decoded as it plays.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct Mix_Music(c_void);
+
+/// If a channel or the music is fading in or out.
+///
+/// See `MIX_*FADING*` constants.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Mix_Fading(pub c_int);
+#[allow(missing_docs)]
+pub const MIX_NO_FADING: Mix_Fading = Mix_Fading(0);
+#[allow(missing_docs)]
+pub const MIX_FADING_OUT: Mix_Fading = Mix_Fading(1);
+#[allow(missing_docs)]
+pub const MIX_FADING_IN: Mix_Fading = Mix_Fading(2);
+impl_names_for_tuple_newtype!(Mix_Fading {
+  MIX_NO_FADING,
+  MIX_FADING_OUT,
+  MIX_FADING_IN,
+});
+
+/// An effect that processes a channel's audio before it's mixed.
+///
+/// * `chan` the channel (or [`MIX_CHANNEL_POST`]).
+/// * `stream` the audio to change in place, in the format given by
+///   [`Mix_QuerySpec`].
+/// * `len` the length of `stream` in bytes.
+/// * `udata` the `arg` given to [`Mix_RegisterEffect`].
+///
+/// This is called from the audio thread.
+pub type Mix_EffectFunc_t = Option<
+  unsafe extern "C" fn(
+    chan: c_int,
+    stream: *mut c_void,
+    len: c_int,
+    udata: *mut c_void,
+  ),
+>;
+
+/// Called when an effect is removed, or its channel finishes playing.
+pub type Mix_EffectDone_t =
+  Option<unsafe extern "C" fn(chan: c_int, udata: *mut c_void)>;
+
+sdl_extern! {
+  /// Loads the support for the decoders given.
+  ///
+  /// **Returns:** the flags of the decoders that are now loaded. Check this
+  /// against the flags you asked for to see if any failed.
+  pub fn Mix_Init(flags: MIX_InitFlags) -> c_int;
+
+  /// Unloads the support for all decoders.
+  pub fn Mix_Quit();
+
+  /// Opens the default audio device for playback.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_OpenAudio(
+    frequency: c_int, format: SDL_AudioFormat, channels: c_int,
+    chunksize: c_int,
+  ) -> c_int;
+
+  /// Opens an audio device for playback.
+  ///
+  /// * `frequency` the sample rate, in Hz.
+  /// * `format` the sample format.
+  /// * `channels` the number of speaker channels (1 is mono, 2 is stereo).
+  /// * `chunksize` the audio buffer size in sample frames.
+  /// * `device` the name of the device (see `SDL_GetAudioDeviceName`), or NULL
+  ///   for the default.
+  /// * `allowed_changes` what can be different from the request (as with
+  ///   [`SDL_OpenAudioDevice`]), use [`Mix_QuerySpec`] to see what you got.
+  ///
+  /// SDL's audio subsystem is initialized by this if it wasn't already.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_OpenAudioDevice(
+    frequency: c_int, format: SDL_AudioFormat, channels: c_int,
+    chunksize: c_int, device: *const c_char, allowed_changes: c_int,
+  ) -> c_int;
+
+  /// Gets the format that the audio device was actually opened with.
+  ///
+  /// Any of the output pointers can be NULL if you don't need that value.
+  ///
+  /// **Returns:** 0 if the audio device isn't open, otherwise non-zero.
+  pub fn Mix_QuerySpec(
+    frequency: *mut c_int, format: *mut SDL_AudioFormat, channels: *mut c_int,
+  ) -> c_int;
+
+  /// Closes the audio device (once for each time it was opened).
+  pub fn Mix_CloseAudio();
+
+  /// Changes the number of mixing channels.
+  ///
+  /// Channels past the new number are halted. Pass -1 to only get the
+  /// current number.
+  ///
+  /// **Returns:** the number of channels now allocated.
+  pub fn Mix_AllocateChannels(numchans: c_int) -> c_int;
+
+  /// Loads a sample from a seekable SDL data stream, and decodes it to the
+  /// output format.
+  ///
+  /// If `freesrc` is non-zero, the stream will be closed after being read.
+  ///
+  /// The chunk should be freed with [`Mix_FreeChunk`].
+  ///
+  /// **Returns:** the new chunk, or NULL if there was an error.
+  pub fn Mix_LoadWAV_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut Mix_Chunk;
+
+  /// Makes a chunk from sample data that's already in the output format.
+  ///
+  /// The chunk doesn't copy or own `mem`, which must stay valid until the
+  /// chunk is freed with [`Mix_FreeChunk`].
+  ///
+  /// **Returns:** the new chunk, or NULL if there was an error.
+  pub fn Mix_QuickLoad_RAW(mem: *mut Uint8, len: Uint32) -> *mut Mix_Chunk;
+
+  /// Frees a chunk, halting any channel that's playing it.
+  pub fn Mix_FreeChunk(chunk: *mut Mix_Chunk);
+
+  /// Loads music from a file.
+  ///
+  /// The music should be freed with [`Mix_FreeMusic`].
+  ///
+  /// **Returns:** the new music, or NULL if there was an error.
+  pub fn Mix_LoadMUS(file: *const c_char) -> *mut Mix_Music;
+
+  /// Loads music from a seekable SDL data stream.
+  ///
+  /// If `freesrc` is non-zero, the stream will be closed when the music is
+  /// freed (or now, if there's an error). Otherwise the stream must stay open
+  /// until the music is freed, since it's read as the music plays.
+  ///
+  /// **Returns:** the new music, or NULL if there was an error.
+  pub fn Mix_LoadMUS_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut Mix_Music;
+
+  /// Frees music, halting it first if it's playing.
+  pub fn Mix_FreeMusic(music: *mut Mix_Music);
+
+  /// Sets a function to call when any channel finishes playing.
+  ///
+  /// It's called from the audio thread, so it must not call any mixer
+  /// functions. Pass `None` to remove it.
+  pub fn Mix_ChannelFinished(
+    channel_finished: Option<unsafe extern "C" fn(channel: c_int)>,
+  );
+
+  /// Sets a function to call when the music finishes playing.
+  ///
+  /// It's called from the audio thread, so it must not call any mixer
+  /// functions. Pass `None` to remove it.
+  pub fn Mix_HookMusicFinished(music_finished: Option<unsafe extern "C" fn()>);
+
+  /// Adds an effect to a channel (or to the final mix, with
+  /// [`MIX_CHANNEL_POST`]).
+  ///
+  /// Effects run in the order they're registered. The effect stays until it's
+  /// unregistered or the channel finishes playing, and then `d` (if any) is
+  /// called.
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_RegisterEffect(
+    chan: c_int, f: Mix_EffectFunc_t, d: Mix_EffectDone_t, arg: *mut c_void,
+  ) -> c_int;
+
+  /// Removes the first registration of an effect from a channel.
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_UnregisterEffect(channel: c_int, f: Mix_EffectFunc_t) -> c_int;
+
+  /// Removes all effects from a channel.
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_UnregisterAllEffects(channel: c_int) -> c_int;
+
+  /// Sets a function that gets the final mixed output, after all the effects.
+  ///
+  /// Pass `None` to remove it.
+  pub fn Mix_SetPostMix(
+    mix_func: Option<
+      unsafe extern "C" fn(udata: *mut c_void, stream: *mut Uint8, len: c_int),
+    >,
+    arg: *mut c_void,
+  );
+
+  /// A built-in effect that sets the left and right volume of a channel (255
+  /// is full volume, and `255, 255` removes the effect).
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_SetPanning(channel: c_int, left: Uint8, right: Uint8) -> c_int;
+
+  /// A built-in effect that positions a channel's sound around the listener.
+  ///
+  /// * `angle` in degrees, with 0 straight ahead and going clockwise.
+  /// * `distance` from 0 (close, or the effect is removed) to 255 (far).
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_SetPosition(
+    channel: c_int, angle: Sint16, distance: Uint8,
+  ) -> c_int;
+
+  /// A built-in effect that quiets a channel with distance, from 0 (close, or
+  /// the effect is removed) to 255 (far).
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_SetDistance(channel: c_int, distance: Uint8) -> c_int;
+
+  /// A built-in effect that swaps the left and right of a channel (if `flip`
+  /// is non-zero, otherwise the effect is removed).
+  ///
+  /// **Returns:** non-zero on success, or zero on error.
+  pub fn Mix_SetReverseStereo(channel: c_int, flip: c_int) -> c_int;
+
+  /// Plays a chunk on a channel.
+  ///
+  /// * `channel` the channel, or -1 for the first free channel.
+  /// * `loops` the number of extra times to play it, or -1 to loop forever.
+  ///
+  /// **Returns:** the channel that's playing it, or -1 on error.
+  pub fn Mix_PlayChannel(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannel`], but stops after at most `ticks` milliseconds (or
+  /// -1 for no limit).
+  pub fn Mix_PlayChannelTimed(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ticks: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannel`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInChannel(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ms: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannelTimed`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInChannelTimed(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ms: c_int,
+    ticks: c_int,
+  ) -> c_int;
+
+  /// Plays music, stopping any music already playing.
+  ///
+  /// * `loops` the number of times to play it, or -1 to loop forever (0 also
+  ///   plays it once).
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_PlayMusic(music: *mut Mix_Music, loops: c_int) -> c_int;
+
+  /// As [`Mix_PlayMusic`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInMusic(
+    music: *mut Mix_Music, loops: c_int, ms: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_FadeInMusic`], but starts from `position` (see
+  /// [`Mix_SetMusicPosition`]).
+  pub fn Mix_FadeInMusicPos(
+    music: *mut Mix_Music, loops: c_int, ms: c_int, position: c_double,
+  ) -> c_int;
+
+  /// Sets the volume of a channel (or all channels, with -1).
+  ///
+  /// Pass a negative volume to only get the current volume.
+  ///
+  /// **Returns:** the previous volume (the average, for all channels).
+  pub fn Mix_Volume(channel: c_int, volume: c_int) -> c_int;
+
+  /// Sets the volume of a chunk, which is multiplied with the channel volume.
+  ///
+  /// Pass a negative volume to only get the current volume.
+  ///
+  /// **Returns:** the previous volume.
+  pub fn Mix_VolumeChunk(chunk: *mut Mix_Chunk, volume: c_int) -> c_int;
+
+  /// Sets the volume of the music.
+  ///
+  /// Pass a negative volume to only get the current volume.
+  ///
+  /// **Returns:** the previous volume.
+  pub fn Mix_VolumeMusic(volume: c_int) -> c_int;
+
+  /// Stops a channel (or all channels, with -1).
+  ///
+  /// **Returns:** 0.
+  pub fn Mix_HaltChannel(channel: c_int) -> c_int;
+
+  /// Stops the music.
+  ///
+  /// **Returns:** 0.
+  pub fn Mix_HaltMusic() -> c_int;
+
+  /// Stops a channel (or all channels, with -1) after `ticks` milliseconds, or
+  /// cancels that with `ticks` of -1.
+  ///
+  /// **Returns:** the number of channels changed.
+  pub fn Mix_ExpireChannel(channel: c_int, ticks: c_int) -> c_int;
+
+  /// Fades out a channel (or all channels, with -1) over `ms` milliseconds.
+  ///
+  /// **Returns:** the number of channels that will fade out.
+  pub fn Mix_FadeOutChannel(which: c_int, ms: c_int) -> c_int;
+
+  /// Fades out the music over `ms` milliseconds.
+  ///
+  /// **Returns:** non-zero on success, or zero if there's no music playing.
+  pub fn Mix_FadeOutMusic(ms: c_int) -> c_int;
+
+  /// If the music is fading in or out.
+  pub fn Mix_FadingMusic() -> Mix_Fading;
+
+  /// If a channel is fading in or out.
+  pub fn Mix_FadingChannel(which: c_int) -> Mix_Fading;
+
+  /// Pauses a channel (or all channels, with -1).
+  pub fn Mix_Pause(channel: c_int);
+
+  /// Resumes a paused channel (or all channels, with -1).
+  pub fn Mix_Resume(channel: c_int);
+
+  /// Checks if a channel is paused, or the number of paused channels with -1.
+  pub fn Mix_Paused(channel: c_int) -> c_int;
+
+  /// Pauses the music.
+  pub fn Mix_PauseMusic();
+
+  /// Resumes paused music.
+  pub fn Mix_ResumeMusic();
+
+  /// Restarts the music from the beginning.
+  pub fn Mix_RewindMusic();
+
+  /// Checks if the music is paused (non-zero means paused).
+  pub fn Mix_PausedMusic() -> c_int;
+
+  /// Jumps to a position in the music.
+  ///
+  /// The meaning of `position` depends on the music format, but for most it's
+  /// the number of seconds from the start.
+  ///
+  /// **Returns:** 0 on success, or -1 if it's not supported for the format.
+  pub fn Mix_SetMusicPosition(position: c_double) -> c_int;
+
+  /// Checks if a channel is playing, or the number of playing channels with
+  /// -1.
+  ///
+  /// Paused channels still count as playing.
+  pub fn Mix_Playing(channel: c_int) -> c_int;
+
+  /// Checks if the music is playing (non-zero means playing).
+  pub fn Mix_PlayingMusic() -> c_int;
+
+  /// Gets the chunk that was most recently played on a channel, or NULL.
+  pub fn Mix_GetChunk(channel: c_int) -> *mut Mix_Chunk;
+}
@@ -9,7 +9,7 @@ use crate::{c_int, c_void, stdinc::*, surface::*, video::*};
 pub struct SDL_Cursor(c_void);
 
 /// Used with [`SDL_CreateSystemCursor`]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_SystemCursor(pub i32);
 
@@ -37,12 +37,26 @@ pub const SDL_SYSTEM_CURSOR_SIZEALL: SDL_SystemCursor = SDL_SystemCursor(9);
 pub const SDL_SYSTEM_CURSOR_NO: SDL_SystemCursor = SDL_SystemCursor(10);
 /// Hand
 pub const SDL_SYSTEM_CURSOR_HAND: SDL_SystemCursor = SDL_SystemCursor(11);
+impl_names_for_tuple_newtype!(SDL_SystemCursor {
+  SDL_SYSTEM_CURSOR_ARROW,
+  SDL_SYSTEM_CURSOR_IBEAM,
+  SDL_SYSTEM_CURSOR_WAIT,
+  SDL_SYSTEM_CURSOR_CROSSHAIR,
+  SDL_SYSTEM_CURSOR_WAITARROW,
+  SDL_SYSTEM_CURSOR_SIZENWSE,
+  SDL_SYSTEM_CURSOR_SIZENESW,
+  SDL_SYSTEM_CURSOR_SIZEWE,
+  SDL_SYSTEM_CURSOR_SIZENS,
+  SDL_SYSTEM_CURSOR_SIZEALL,
+  SDL_SYSTEM_CURSOR_NO,
+  SDL_SYSTEM_CURSOR_HAND,
+});
 
 /// Used for array size purposes and such.
 pub const SDL_NUM_SYSTEM_CURSORS: usize = 12;
 
 /// Scroll direction types for the Scroll event
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_MouseWheelDirection(pub i32);
 
@@ -52,8 +66,12 @@ pub const SDL_MOUSEWHEEL_NORMAL: SDL_MouseWheelDirection =
 /// The scroll direction is flipped / natural
 pub const SDL_MOUSEWHEEL_FLIPPED: SDL_MouseWheelDirection =
   SDL_MouseWheelDirection(1);
+impl_names_for_tuple_newtype!(SDL_MouseWheelDirection {
+  SDL_MOUSEWHEEL_NORMAL,
+  SDL_MOUSEWHEEL_FLIPPED,
+});
 
-extern "C" {
+sdl_extern! {
   /// Get the window which currently has mouse focus.
   pub fn SDL_GetMouseFocus() -> *mut SDL_Window;
 
@@ -0,0 +1,371 @@
+//! Simple cross-platform networking, using the `SDL2_net` library.
+//!
+//! Requires the `net` feature, and `SDL2_net` must be installed.
+//!
+//! Only IPv4 is supported. The `host` and `port` of an [`IPaddress`] are kept
+//! in network byte order (big-endian), but the `port` arguments of functions
+//! are in native byte order. Errors from these functions are reported through
+//! [`SDL_GetErrorMsg`].
+
+use crate::{c_char, c_int, c_void, stdinc::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// An IPv4 address and port, both in network byte order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct IPaddress {
+  /// 32-bit IPv4 host address
+  pub host: Uint32,
+  /// 16-bit protocol port
+  pub port: Uint16,
+}
+
+/// Listen on all addresses (for [`SDLNet_ResolveHost`] with a NULL host).
+pub const INADDR_ANY: Uint32 = 0x00000000;
+/// Given by [`SDLNet_ResolveHost`] when the host couldn't be resolved.
+pub const INADDR_NONE: Uint32 = 0xFFFFFFFF;
+/// `127.0.0.1`, in native byte order.
+pub const INADDR_LOOPBACK: Uint32 = 0x7f000001;
+/// Sends to the whole local network (for UDP).
+pub const INADDR_BROADCAST: Uint32 = 0xFFFFFFFF;
+
+/// The maximum number of channels on a UDP socket.
+pub const SDLNET_MAX_UDPCHANNELS: c_int = 32;
+/// The maximum number of addresses bound to a single UDP socket channel.
+pub const SDLNET_MAX_UDPADDRESSES: c_int = 4;
+
+/// A handle to a TCP socket.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct TCPsocket(pub *mut c_void);
+impl TCPsocket {
+  /// Checks if the socket pointer is null.
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// A handle to a UDP socket.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct UDPsocket(pub *mut c_void);
+impl UDPsocket {
+  /// Checks if the socket pointer is null.
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// A UDP packet, for sending or receiving.
+///
+/// Allocate these with [`SDLNet_AllocPacket`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct UDPpacket {
+  /// The channel to send to (or -1 to use `address`), or the channel the
+  /// packet was received on.
+  pub channel: c_int,
+  /// The packet data.
+  pub data: *mut Uint8,
+  /// The length of the packet data.
+  pub len: c_int,
+  /// The size of the data buffer.
+  pub maxlen: c_int,
+  /// Status after sending: the number of bytes sent, or -1 on error.
+  pub status: c_int,
+  /// The address the packet was received from, or is sent to.
+  pub address: IPaddress,
+}
+
+/// A handle to a set of sockets, to wait on them all at once.
+///
+/// See [`SDLNet_AllocSocketSet`]
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct SDLNet_SocketSet(pub *mut c_void);
+impl SDLNet_SocketSet {
+  /// Checks if the socket set pointer is null.
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// The start of every socket's data, which is all that socket sets use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct _SDLNet_GenericSocket {
+  /// Non-zero when [`SDLNet_CheckSockets`] found data waiting on the socket.
+  pub ready: c_int,
+}
+
+/// Either a [`TCPsocket`] or a [`UDPsocket`], for use with socket sets.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct SDLNet_GenericSocket(pub *mut _SDLNet_GenericSocket);
+impl From<TCPsocket> for SDLNet_GenericSocket {
+  #[inline]
+  fn from(sock: TCPsocket) -> Self {
+    Self(sock.0.cast())
+  }
+}
+impl From<UDPsocket> for SDLNet_GenericSocket {
+  #[inline]
+  fn from(sock: UDPsocket) -> Self {
+    Self(sock.0.cast())
+  }
+}
+
+sdl_extern! {
+  /// Initializes the library.
+  ///
+  /// Each call must be balanced by a call to [`SDLNet_Quit`].
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn SDLNet_Init() -> c_int;
+
+  /// Shuts down the library (once every [`SDLNet_Init`] has been balanced).
+  pub fn SDLNet_Quit();
+
+  /// Resolves a host name and port to an IP address.
+  ///
+  /// If `host` is NULL then the address is [`INADDR_ANY`], which is what a
+  /// server listening on `port` should use.
+  ///
+  /// **Returns:** 0 on success, or -1 if the host couldn't be resolved (and
+  /// the `host` of the address is set to [`INADDR_NONE`]).
+  pub fn SDLNet_ResolveHost(
+    address: *mut IPaddress, host: *const c_char, port: Uint16,
+  ) -> c_int;
+
+  /// Resolves an IP address to a host name.
+  ///
+  /// **Returns:** the host name (in a static buffer, overwritten by the next
+  /// call), or NULL on error.
+  pub fn SDLNet_ResolveIP(ip: *const IPaddress) -> *const c_char;
+
+  /// Gets the addresses of the local network interfaces.
+  ///
+  /// **Returns:** the number of addresses written into `addresses`, up to
+  /// `maxcount`.
+  pub fn SDLNet_GetLocalAddresses(
+    addresses: *mut IPaddress, maxcount: c_int,
+  ) -> c_int;
+
+  /// Opens a TCP socket.
+  ///
+  /// If the `host` of `ip` is [`INADDR_ANY`] (or [`INADDR_NONE`] or
+  /// [`INADDR_BROADCAST`]) this opens a server socket listening on the port,
+  /// otherwise it opens a connection to the address.
+  ///
+  /// **Returns:** the new socket, or a null socket on error.
+  pub fn SDLNet_TCP_Open(ip: *mut IPaddress) -> TCPsocket;
+
+  /// Accepts an incoming connection on a server socket, without waiting.
+  ///
+  /// **Returns:** the new socket, or a null socket if there's no connection
+  /// waiting.
+  pub fn SDLNet_TCP_Accept(server: TCPsocket) -> TCPsocket;
+
+  /// Gets the address of the other end of a connected socket.
+  ///
+  /// **Returns:** the address (borrowed), or NULL for a server socket.
+  pub fn SDLNet_TCP_GetPeerAddress(sock: TCPsocket) -> *mut IPaddress;
+
+  /// Sends data over a connected socket, waiting until it's all sent.
+  ///
+  /// **Returns:** the number of bytes sent. Less than `len` means there was an
+  /// error (such as the connection closing).
+  pub fn SDLNet_TCP_Send(
+    sock: TCPsocket, data: *const c_void, len: c_int,
+  ) -> c_int;
+
+  /// Receives up to `maxlen` bytes from a connected socket, waiting until at
+  /// least some data arrives.
+  ///
+  /// **Returns:** the number of bytes received, or 0 or less if there was an
+  /// error (such as the connection closing).
+  pub fn SDLNet_TCP_Recv(
+    sock: TCPsocket, data: *mut c_void, maxlen: c_int,
+  ) -> c_int;
+
+  /// Closes a TCP socket.
+  pub fn SDLNet_TCP_Close(sock: TCPsocket);
+
+  /// Allocates a packet with a data buffer of `size` bytes.
+  ///
+  /// The packet should be freed with [`SDLNet_FreePacket`].
+  ///
+  /// **Returns:** the new packet, or NULL on error.
+  pub fn SDLNet_AllocPacket(size: c_int) -> *mut UDPpacket;
+
+  /// Changes the size of a packet's data buffer.
+  ///
+  /// **Returns:** the new size, or the old size if there was an error.
+  pub fn SDLNet_ResizePacket(packet: *mut UDPpacket, newsize: c_int) -> c_int;
+
+  /// Frees a packet.
+  pub fn SDLNet_FreePacket(packet: *mut UDPpacket);
+
+  /// Allocates a NULL terminated array of `howmany` packets, each with `size`
+  /// bytes of data buffer.
+  ///
+  /// The array should be freed with [`SDLNet_FreePacketV`].
+  ///
+  /// **Returns:** the new array, or NULL on error.
+  pub fn SDLNet_AllocPacketV(
+    howmany: c_int, size: c_int,
+  ) -> *mut *mut UDPpacket;
+
+  /// Frees an array of packets from [`SDLNet_AllocPacketV`].
+  pub fn SDLNet_FreePacketV(packetV: *mut *mut UDPpacket);
+
+  /// Opens a UDP socket on a port, or on any free port if `port` is 0.
+  ///
+  /// **Returns:** the new socket, or a null socket on error.
+  pub fn SDLNet_UDP_Open(port: Uint16) -> UDPsocket;
+
+  /// Simulates packet loss for testing, from 0 to 100 percent.
+  pub fn SDLNet_UDP_SetPacketLoss(sock: UDPsocket, percent: c_int);
+
+  /// Binds an address to a channel of a socket.
+  ///
+  /// Packets sent on the channel go to all of its addresses, and packets
+  /// received from those addresses are marked with the channel.
+  ///
+  /// * `channel` the channel, or -1 for the first free channel.
+  ///
+  /// **Returns:** the channel that was bound, or -1 on error.
+  pub fn SDLNet_UDP_Bind(
+    sock: UDPsocket, channel: c_int, address: *const IPaddress,
+  ) -> c_int;
+
+  /// Unbinds all the addresses from a channel of a socket.
+  pub fn SDLNet_UDP_Unbind(sock: UDPsocket, channel: c_int);
+
+  /// Gets the primary address bound to a channel, or the socket's own address
+  /// with a channel of -1.
+  ///
+  /// **Returns:** the address (borrowed), or NULL if the channel isn't bound.
+  pub fn SDLNet_UDP_GetPeerAddress(
+    sock: UDPsocket, channel: c_int,
+  ) -> *mut IPaddress;
+
+  /// Sends several packets, each to its `channel` (or its `address` if the
+  /// channel is -1).
+  ///
+  /// The `status` of each packet is set to the bytes sent, or -1 on error.
+  ///
+  /// **Returns:** the number of packets sent.
+  pub fn SDLNet_UDP_SendV(
+    sock: UDPsocket, packets: *mut *mut UDPpacket, npackets: c_int,
+  ) -> c_int;
+
+  /// Sends a packet to a channel (or to the packet's `address` if `channel`
+  /// is -1).
+  ///
+  /// **Returns:** the number of destinations the packet was sent to, or 0 on
+  /// error.
+  pub fn SDLNet_UDP_Send(
+    sock: UDPsocket, channel: c_int, packet: *mut UDPpacket,
+  ) -> c_int;
+
+  /// Receives packets into a NULL terminated array, without waiting.
+  ///
+  /// **Returns:** the number of packets received, or -1 on error.
+  pub fn SDLNet_UDP_RecvV(
+    sock: UDPsocket, packets: *mut *mut UDPpacket,
+  ) -> c_int;
+
+  /// Receives one packet, without waiting.
+  ///
+  /// **Returns:** 1 if a packet was received, 0 if there wasn't one waiting,
+  /// or -1 on error.
+  pub fn SDLNet_UDP_Recv(sock: UDPsocket, packet: *mut UDPpacket) -> c_int;
+
+  /// Closes a UDP socket.
+  pub fn SDLNet_UDP_Close(sock: UDPsocket);
+
+  /// Allocates a socket set that can hold up to `maxsockets` sockets.
+  ///
+  /// The set should be freed with [`SDLNet_FreeSocketSet`].
+  ///
+  /// **Returns:** the new set, or a null set on error.
+  pub fn SDLNet_AllocSocketSet(maxsockets: c_int) -> SDLNet_SocketSet;
+
+  /// Adds a socket to a set.
+  ///
+  /// **Returns:** the number of sockets in the set, or -1 if it's full.
+  pub fn SDLNet_AddSocket(
+    set: SDLNet_SocketSet, sock: SDLNet_GenericSocket,
+  ) -> c_int;
+
+  /// Removes a socket from a set.
+  ///
+  /// **Returns:** the number of sockets left in the set, or -1 if the socket
+  /// wasn't in the set.
+  pub fn SDLNet_DelSocket(
+    set: SDLNet_SocketSet, sock: SDLNet_GenericSocket,
+  ) -> c_int;
+
+  /// Waits up to `timeout` milliseconds for any socket in the set to have data
+  /// waiting, and marks the sockets that do (see [`SDLNet_SocketReady`]).
+  ///
+  /// **Returns:** the number of sockets with data waiting, or -1 on error.
+  pub fn SDLNet_CheckSockets(set: SDLNet_SocketSet, timeout: Uint32) -> c_int;
+
+  /// Frees a socket set (but not the sockets in it).
+  pub fn SDLNet_FreeSocketSet(set: SDLNet_SocketSet);
+}
+
+/// Adds a TCP socket to a set.
+///
+/// See [`SDLNet_AddSocket`]
+#[inline]
+pub unsafe fn SDLNet_TCP_AddSocket(
+  set: SDLNet_SocketSet, sock: TCPsocket,
+) -> c_int {
+  SDLNet_AddSocket(set, sock.into())
+}
+
+/// Adds a UDP socket to a set.
+///
+/// See [`SDLNet_AddSocket`]
+#[inline]
+pub unsafe fn SDLNet_UDP_AddSocket(
+  set: SDLNet_SocketSet, sock: UDPsocket,
+) -> c_int {
+  SDLNet_AddSocket(set, sock.into())
+}
+
+/// Removes a TCP socket from a set.
+///
+/// See [`SDLNet_DelSocket`]
+#[inline]
+pub unsafe fn SDLNet_TCP_DelSocket(
+  set: SDLNet_SocketSet, sock: TCPsocket,
+) -> c_int {
+  SDLNet_DelSocket(set, sock.into())
+}
+
+/// Removes a UDP socket from a set.
+///
+/// See [`SDLNet_DelSocket`]
+#[inline]
+pub unsafe fn SDLNet_UDP_DelSocket(
+  set: SDLNet_SocketSet, sock: UDPsocket,
+) -> c_int {
+  SDLNet_DelSocket(set, sock.into())
+}
+
+/// If the last [`SDLNet_CheckSockets`] found data waiting on this socket.
+#[inline]
+#[must_use]
+pub unsafe fn SDLNet_SocketReady(
+  sock: impl Into<SDLNet_GenericSocket>,
+) -> bool {
+  let sock: SDLNet_GenericSocket = sock.into();
+  !sock.0.is_null() && (*sock.0).ready != 0
+}
@@ -0,0 +1,243 @@
+//! Functions newer than SDL 2.0.14, resolved at runtime.
+//!
+//! The `extern "C"` declarations behind the `sdl_*` crate features are
+//! resolved by the linker, so a program using them will refuse to start
+//! against an older SDL. The functions in this module instead look the symbol
+//! up in the already-loaded SDL library (via [`SDL_LoadObject`] and
+//! [`SDL_LoadFunction`]) the first time they're called, and return `None` if
+//! the SDL in use doesn't have it. With the `dynamic_loading` feature the
+//! lookup uses the library opened by `load` instead.
+//!
+//! ```no_run
+//! # use fermium::{prelude::*, optional};
+//! # let (renderer, vertices): (*mut SDL_Renderer, [SDL_Vertex; 3]) = todo!();
+//! if let Some(render_geometry) = optional::SDL_RenderGeometry() {
+//!   unsafe {
+//!     render_geometry(
+//!       renderer,
+//!       core::ptr::null_mut(),
+//!       vertices.as_ptr(),
+//!       vertices.len() as _,
+//!       core::ptr::null(),
+//!       0,
+//!     )
+//!   };
+//! } else {
+//!   // fall back to something the older SDL can do.
+//! }
+//! ```
+//!
+//! When the crate feature for a function's version is enabled the function is
+//! known to exist at link time, so it's returned directly without a lookup.
+//! With `dynamic_loading` the crate's own functions aren't `extern "C"`, so the
+//! symbol is always looked up.
+//!
+//! A failed lookup leaves a message for [`SDL_GetErrorMsg`] just like any other
+//! failed [`SDL_LoadFunction`] call would. Each function is only looked up
+//! once, after that the result (found or not) is cached.
+
+// with every version feature on, nothing needs to be looked up at runtime.
+#![cfg_attr(
+  all(feature = "sdl_2_26", not(feature = "dynamic_loading")),
+  allow(dead_code)
+)]
+
+use core::{
+  ptr::null_mut,
+  sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{
+  audio::*, c_char, c_float, c_int, c_void, gamecontroller::*, joystick::*,
+  pixels::*, rect::*, renderer::*, sensor::*, stdinc::*, surface::*, video::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, loadso::*};
+
+/// The names the SDL library might already be loaded under.
+///
+/// On unix-like systems a null name gives the handle of the main program,
+/// which can see the symbols of every library loaded along with it, so that's
+/// tried first.
+#[cfg(all(windows, not(feature = "dynamic_loading")))]
+const SDL_LIBRARY_NAMES: &[*const c_char] = &[b"SDL2.dll\0".as_ptr().cast()];
+#[cfg(all(target_os = "macos", not(feature = "dynamic_loading")))]
+const SDL_LIBRARY_NAMES: &[*const c_char] = &[
+  core::ptr::null(),
+  b"libSDL2-2.0.0.dylib\0".as_ptr().cast(),
+  b"libSDL2.dylib\0".as_ptr().cast(),
+];
+#[cfg(not(any(windows, target_os = "macos", feature = "dynamic_loading")))]
+const SDL_LIBRARY_NAMES: &[*const c_char] = &[
+  core::ptr::null(),
+  b"libSDL2-2.0.so.0\0".as_ptr().cast(),
+  b"libSDL2.so\0".as_ptr().cast(),
+];
+
+/// Marks a cache slot as "looked up, and not found".
+static NOT_FOUND: u8 = 0;
+
+fn not_found() -> *mut c_void {
+  core::ptr::addr_of!(NOT_FOUND) as *mut c_void
+}
+
+/// Gets the handle of the loaded SDL library.
+///
+/// The handle is never unloaded, it only adds a reference to a library that
+/// is already in the process anyway.
+#[cfg(not(feature = "dynamic_loading"))]
+fn sdl_handle() -> *mut c_void {
+  static HANDLE: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+  let handle = HANDLE.load(Ordering::Acquire);
+  if !handle.is_null() {
+    return handle;
+  }
+  let handle = SDL_LIBRARY_NAMES
+    .iter()
+    .map(|&name| unsafe { SDL_LoadObject(name) })
+    .find(|handle| !handle.is_null())
+    .unwrap_or(null_mut());
+  if !handle.is_null() {
+    if let Err(already) = HANDLE.compare_exchange(
+      null_mut(),
+      handle,
+      Ordering::AcqRel,
+      Ordering::Acquire,
+    ) {
+      // another thread got there first, drop our extra reference.
+      unsafe { SDL_UnloadObject(handle) };
+      return already;
+    }
+  }
+  handle
+}
+
+#[cfg(not(feature = "dynamic_loading"))]
+fn lookup(name: *const c_char) -> *mut c_void {
+  let handle = sdl_handle();
+  if handle.is_null() {
+    null_mut()
+  } else {
+    unsafe { SDL_LoadFunction(handle, name) }
+  }
+}
+
+/// With `dynamic_loading` the library was opened by
+/// [`load`](crate::load), so the lookup goes through that.
+#[cfg(feature = "dynamic_loading")]
+fn lookup(name: *const c_char) -> *mut c_void {
+  if !crate::dynamic_loading::is_loaded() {
+    crate::dynamic_loading::not_loaded("fermium::optional");
+  }
+  crate::dynamic_loading::symbol(name)
+}
+
+/// Looks up `name` once, caching the outcome in `slot`.
+///
+/// Returns null if the function isn't available.
+fn resolve(slot: &AtomicPtr<c_void>, name: *const c_char) -> *mut c_void {
+  let mut p = slot.load(Ordering::Acquire);
+  if p.is_null() {
+    p = lookup(name);
+    if p.is_null() {
+      p = not_found();
+    }
+    slot.store(p, Ordering::Release);
+  }
+  if p == not_found() {
+    null_mut()
+  } else {
+    p
+  }
+}
+
+macro_rules! optional_fns {
+  ($(
+    $feature:literal $module:ident :: $name:ident(
+      $($arg:ident : $t:ty),* $(,)?
+    ) $(-> $ret:ty)?;
+  )*) => {
+    $(
+      #[doc = concat!(
+        "Runtime lookup of `", stringify!($name), "` (added in ",
+        "the `", $feature, "` feature)."
+      )]
+      #[doc = ""]
+      #[doc = concat!(
+        "See [`", stringify!($module), "`](crate::", stringify!($module),
+        ") for the function's docs."
+      )]
+      #[inline]
+      #[must_use]
+      #[allow(non_snake_case)]
+      pub fn $name() -> Option<unsafe extern "C" fn($($t),*) $(-> $ret)?> {
+        #[cfg(all(feature = $feature, not(feature = "dynamic_loading")))]
+        {
+          Some(crate::$module::$name as unsafe extern "C" fn($($t),*) $(-> $ret)?)
+        }
+        #[cfg(any(not(feature = $feature), feature = "dynamic_loading"))]
+        {
+          static SLOT: AtomicPtr<c_void> = AtomicPtr::new(null_mut());
+          let p =
+            resolve(&SLOT, concat!(stringify!($name), "\0").as_ptr().cast());
+          if p.is_null() {
+            None
+          } else {
+            // Safety: the symbol has this signature in every SDL that has it.
+            Some(unsafe {
+              core::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C" fn($($t),*) $(-> $ret)?,
+              >(p)
+            })
+          }
+        }
+      }
+    )*
+  };
+}
+
+optional_fns! {
+  // SDL 2.0.16
+  "sdl_2_0_16" audio::SDL_GetAudioDeviceSpec(index: c_int, iscapture: c_int, spec: *mut SDL_AudioSpec) -> c_int;
+  "sdl_2_0_16" gamecontroller::SDL_GameControllerSendEffect(gamecontroller: *mut SDL_GameController, data: *const c_void, size: c_int) -> c_int;
+  "sdl_2_0_16" gamecontroller::SDL_GameControllerGetSensorDataRate(gamecontroller: *mut SDL_GameController, type_: SDL_SensorType) -> c_float;
+  "sdl_2_0_16" joystick::SDL_JoystickSendEffect(joystick: *mut SDL_Joystick, data: *const c_void, size: c_int) -> c_int;
+  "sdl_2_0_16" renderer::SDL_UpdateNVTexture(texture: *mut SDL_Texture, rect: *const SDL_Rect, Yplane: *const Uint8, Ypitch: c_int, UVplane: *const Uint8, UVpitch: c_int) -> c_int;
+  "sdl_2_0_16" surface::SDL_SoftStretchLinear(src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface, dstrect: *const SDL_Rect) -> c_int;
+  "sdl_2_0_16" video::SDL_FlashWindow(window: *mut SDL_Window, operation: SDL_FlashOperation) -> c_int;
+  "sdl_2_0_16" video::SDL_SetWindowAlwaysOnTop(window: *mut SDL_Window, on_top: SDL_bool);
+  "sdl_2_0_16" video::SDL_SetWindowKeyboardGrab(window: *mut SDL_Window, grabbed: SDL_bool);
+  "sdl_2_0_16" video::SDL_SetWindowMouseGrab(window: *mut SDL_Window, grabbed: SDL_bool);
+  "sdl_2_0_16" video::SDL_GetWindowKeyboardGrab(window: *mut SDL_Window) -> SDL_bool;
+  "sdl_2_0_16" video::SDL_GetWindowMouseGrab(window: *mut SDL_Window) -> SDL_bool;
+
+  // SDL 2.0.18
+  "sdl_2_0_18" renderer::SDL_RenderGeometry(renderer: *mut SDL_Renderer, texture: *mut SDL_Texture, vertices: *const SDL_Vertex, num_vertices: c_int, indices: *const c_int, num_indices: c_int) -> c_int;
+  "sdl_2_0_18" renderer::SDL_RenderGeometryRaw(renderer: *mut SDL_Renderer, texture: *mut SDL_Texture, xy: *const c_float, xy_stride: c_int, color: *const SDL_Color, color_stride: c_int, uv: *const c_float, uv_stride: c_int, num_vertices: c_int, indices: *const c_void, num_indices: c_int, size_indices: c_int) -> c_int;
+
+  // SDL 2.0.22
+  "sdl_2_0_22" keyboard::SDL_ClearComposition();
+  "sdl_2_0_22" keyboard::SDL_IsTextInputShown() -> SDL_bool;
+  "sdl_2_0_22" rect::SDL_HasIntersectionF(A: *const SDL_FRect, B: *const SDL_FRect) -> SDL_bool;
+  "sdl_2_0_22" rect::SDL_IntersectFRect(A: *const SDL_FRect, B: *const SDL_FRect, result: *mut SDL_FRect) -> SDL_bool;
+  "sdl_2_0_22" rect::SDL_UnionFRect(A: *const SDL_FRect, B: *const SDL_FRect, result: *mut SDL_FRect);
+  "sdl_2_0_22" rect::SDL_EncloseFPoints(points: *const SDL_FPoint, count: c_int, clip: *const SDL_FRect, result: *mut SDL_FRect) -> SDL_bool;
+  "sdl_2_0_22" rect::SDL_IntersectFRectAndLine(rect: *const SDL_FRect, X1: *mut c_float, Y1: *mut c_float, X2: *mut c_float, Y2: *mut c_float) -> SDL_bool;
+  "sdl_2_0_22" touch::SDL_GetTouchName(index: c_int) -> *const c_char;
+
+  // SDL 2.24
+  "sdl_2_24" audio::SDL_GetDefaultAudioInfo(name: *mut *mut c_char, spec: *mut SDL_AudioSpec, iscapture: c_int) -> c_int;
+  "sdl_2_24" hints::SDL_ResetHint(name: *const c_char) -> SDL_bool;
+  "sdl_2_24" joystick::SDL_JoystickAttachVirtualEx(desc: *const SDL_VirtualJoystickDesc) -> c_int;
+  "sdl_2_24" keyboard::SDL_ResetKeyboard();
+
+  // SDL 2.26
+  "sdl_2_26" clipboard::SDL_SetPrimarySelectionText(text: *const c_char) -> c_int;
+  "sdl_2_26" clipboard::SDL_GetPrimarySelectionText() -> *mut c_char;
+  "sdl_2_26" clipboard::SDL_HasPrimarySelectionText() -> SDL_bool;
+  "sdl_2_26" hints::SDL_ResetHints();
+  "sdl_2_26" sensor::SDL_SensorGetDataWithTimestamp(sensor: *mut SDL_Sensor, timestamp: *mut Uint64, data: *mut c_float, num_values: c_int) -> c_int;
+}
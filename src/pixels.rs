@@ -12,7 +12,7 @@ pub const SDL_ALPHA_TRANSPARENT: u8 = 0;
 /// Pixel type.
 ///
 /// Instances of this value are called `SDL_PIXELTYPE_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_PixelType(pub u32);
 /// An unknown pixel type.
@@ -28,11 +28,25 @@ pub const SDL_PIXELTYPE_ARRAYU16: SDL_PixelType = SDL_PixelType(8);
 pub const SDL_PIXELTYPE_ARRAYU32: SDL_PixelType = SDL_PixelType(9);
 pub const SDL_PIXELTYPE_ARRAYF16: SDL_PixelType = SDL_PixelType(10);
 pub const SDL_PIXELTYPE_ARRAYF32: SDL_PixelType = SDL_PixelType(11);
+impl_names_for_tuple_newtype!(SDL_PixelType {
+  SDL_PIXELTYPE_UNKNOWN,
+  SDL_PIXELTYPE_INDEX1,
+  SDL_PIXELTYPE_INDEX4,
+  SDL_PIXELTYPE_INDEX8,
+  SDL_PIXELTYPE_PACKED8,
+  SDL_PIXELTYPE_PACKED16,
+  SDL_PIXELTYPE_PACKED32,
+  SDL_PIXELTYPE_ARRAYU8,
+  SDL_PIXELTYPE_ARRAYU16,
+  SDL_PIXELTYPE_ARRAYU32,
+  SDL_PIXELTYPE_ARRAYF16,
+  SDL_PIXELTYPE_ARRAYF32,
+});
 
 /// Bitmap pixel order, high bit -> low bit.
 ///
 /// Instances of this value are called `SDL_BITMAPORDER_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_BitmapOrder(pub u32);
 /// No bitmap ordering.
@@ -41,11 +55,16 @@ pub const SDL_BITMAPORDER_NONE: SDL_BitmapOrder = SDL_BitmapOrder(0);
 pub const SDL_BITMAPORDER_4321: SDL_BitmapOrder = SDL_BitmapOrder(1);
 /// Bitmap ordering from low to high.
 pub const SDL_BITMAPORDER_1234: SDL_BitmapOrder = SDL_BitmapOrder(2);
+impl_names_for_tuple_newtype!(SDL_BitmapOrder {
+  SDL_BITMAPORDER_NONE,
+  SDL_BITMAPORDER_4321,
+  SDL_BITMAPORDER_1234,
+});
 
 /// Packed component order, high bit -> low bit.
 ///
 /// Instances of this value are called `SDL_PACKEDORDER_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_PackedOrder(pub u32);
 pub const SDL_PACKEDORDER_NONE: SDL_PackedOrder = SDL_PackedOrder(0);
@@ -57,11 +76,22 @@ pub const SDL_PACKEDORDER_XBGR: SDL_PackedOrder = SDL_PackedOrder(5);
 pub const SDL_PACKEDORDER_BGRX: SDL_PackedOrder = SDL_PackedOrder(6);
 pub const SDL_PACKEDORDER_ABGR: SDL_PackedOrder = SDL_PackedOrder(7);
 pub const SDL_PACKEDORDER_BGRA: SDL_PackedOrder = SDL_PackedOrder(8);
+impl_names_for_tuple_newtype!(SDL_PackedOrder {
+  SDL_PACKEDORDER_NONE,
+  SDL_PACKEDORDER_XRGB,
+  SDL_PACKEDORDER_RGBX,
+  SDL_PACKEDORDER_ARGB,
+  SDL_PACKEDORDER_RGBA,
+  SDL_PACKEDORDER_XBGR,
+  SDL_PACKEDORDER_BGRX,
+  SDL_PACKEDORDER_ABGR,
+  SDL_PACKEDORDER_BGRA,
+});
 
 /// Array component order, low byte -> high byte.
 ///
 /// Instances of this value are called `SDL_ARRAYORDER_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_ArrayOrder(pub u32);
 pub const SDL_ARRAYORDER_NONE: SDL_ArrayOrder = SDL_ArrayOrder(0);
@@ -71,11 +101,20 @@ pub const SDL_ARRAYORDER_ARGB: SDL_ArrayOrder = SDL_ArrayOrder(3);
 pub const SDL_ARRAYORDER_BGR: SDL_ArrayOrder = SDL_ArrayOrder(4);
 pub const SDL_ARRAYORDER_BGRA: SDL_ArrayOrder = SDL_ArrayOrder(5);
 pub const SDL_ARRAYORDER_ABGR: SDL_ArrayOrder = SDL_ArrayOrder(6);
+impl_names_for_tuple_newtype!(SDL_ArrayOrder {
+  SDL_ARRAYORDER_NONE,
+  SDL_ARRAYORDER_RGB,
+  SDL_ARRAYORDER_RGBA,
+  SDL_ARRAYORDER_ARGB,
+  SDL_ARRAYORDER_BGR,
+  SDL_ARRAYORDER_BGRA,
+  SDL_ARRAYORDER_ABGR,
+});
 
 /// Packed component order, high bit -> low bit.
 ///
 /// Instances of this value are called `SDL_PACKEDLAYOUT_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_PackedLayout(pub u32);
 pub const SDL_PACKEDLAYOUT_NONE: SDL_PackedLayout = SDL_PackedLayout(0);
@@ -87,6 +126,17 @@ pub const SDL_PACKEDLAYOUT_565: SDL_PackedLayout = SDL_PackedLayout(5);
 pub const SDL_PACKEDLAYOUT_8888: SDL_PackedLayout = SDL_PackedLayout(6);
 pub const SDL_PACKEDLAYOUT_2101010: SDL_PackedLayout = SDL_PackedLayout(7);
 pub const SDL_PACKEDLAYOUT_1010102: SDL_PackedLayout = SDL_PackedLayout(8);
+impl_names_for_tuple_newtype!(SDL_PackedLayout {
+  SDL_PACKEDLAYOUT_NONE,
+  SDL_PACKEDLAYOUT_332,
+  SDL_PACKEDLAYOUT_4444,
+  SDL_PACKEDLAYOUT_1555,
+  SDL_PACKEDLAYOUT_5551,
+  SDL_PACKEDLAYOUT_565,
+  SDL_PACKEDLAYOUT_8888,
+  SDL_PACKEDLAYOUT_2101010,
+  SDL_PACKEDLAYOUT_1010102,
+});
 
 /// Converts a FourCC into a pixel format enumeration value.
 #[inline]
@@ -216,7 +266,7 @@ pub const fn SDL_ISPIXELFORMAT_FOURCC(format: SDL_PixelFormatEnum) -> bool {
 /// An enumerated pixel format value.
 ///
 /// Instances of this value are called `SDL_PIXELFORMAT_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_PixelFormatEnum(pub u32);
 pub const SDL_PIXELFORMAT_UNKNOWN: SDL_PixelFormatEnum = SDL_PixelFormatEnum(0);
@@ -485,6 +535,58 @@ pub const SDL_PIXELFORMAT_NV21: SDL_PixelFormatEnum =
 /// Android video texture format
 pub const SDL_PIXELFORMAT_EXTERNAL_OES: SDL_PixelFormatEnum =
   SDL_DEFINE_PIXELFOURCC(b'O', b'E', b'S', b' ');
+impl_names_for_tuple_newtype!(SDL_PixelFormatEnum {
+  SDL_PIXELFORMAT_UNKNOWN,
+  SDL_PIXELFORMAT_INDEX1LSB,
+  SDL_PIXELFORMAT_INDEX1MSB,
+  SDL_PIXELFORMAT_INDEX4LSB,
+  SDL_PIXELFORMAT_INDEX4MSB,
+  SDL_PIXELFORMAT_INDEX8,
+  SDL_PIXELFORMAT_RGB332,
+  SDL_PIXELFORMAT_XRGB4444,
+  SDL_PIXELFORMAT_RGB444,
+  SDL_PIXELFORMAT_XBGR4444,
+  SDL_PIXELFORMAT_BGR444,
+  SDL_PIXELFORMAT_XRGB1555,
+  SDL_PIXELFORMAT_RGB555,
+  SDL_PIXELFORMAT_XBGR1555,
+  SDL_PIXELFORMAT_BGR555,
+  SDL_PIXELFORMAT_ARGB4444,
+  SDL_PIXELFORMAT_RGBA4444,
+  SDL_PIXELFORMAT_ABGR4444,
+  SDL_PIXELFORMAT_BGRA4444,
+  SDL_PIXELFORMAT_ARGB1555,
+  SDL_PIXELFORMAT_RGBA5551,
+  SDL_PIXELFORMAT_ABGR1555,
+  SDL_PIXELFORMAT_BGRA5551,
+  SDL_PIXELFORMAT_RGB565,
+  SDL_PIXELFORMAT_BGR565,
+  SDL_PIXELFORMAT_RGB24,
+  SDL_PIXELFORMAT_BGR24,
+  SDL_PIXELFORMAT_XRGB8888,
+  SDL_PIXELFORMAT_RGB888,
+  SDL_PIXELFORMAT_RGBX8888,
+  SDL_PIXELFORMAT_XBGR8888,
+  SDL_PIXELFORMAT_BGR888,
+  SDL_PIXELFORMAT_BGRX8888,
+  SDL_PIXELFORMAT_ARGB8888,
+  SDL_PIXELFORMAT_RGBA8888,
+  SDL_PIXELFORMAT_ABGR8888,
+  SDL_PIXELFORMAT_BGRA8888,
+  SDL_PIXELFORMAT_ARGB2101010,
+  SDL_PIXELFORMAT_RGBA32,
+  SDL_PIXELFORMAT_ARGB32,
+  SDL_PIXELFORMAT_BGRA32,
+  SDL_PIXELFORMAT_ABGR32,
+  SDL_PIXELFORMAT_YV12,
+  SDL_PIXELFORMAT_IYUV,
+  SDL_PIXELFORMAT_YUY2,
+  SDL_PIXELFORMAT_UYVY,
+  SDL_PIXELFORMAT_YVYU,
+  SDL_PIXELFORMAT_NV12,
+  SDL_PIXELFORMAT_NV21,
+  SDL_PIXELFORMAT_EXTERNAL_OES,
+});
 
 /// An RGBA color value (8-bits per channel).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -538,7 +640,7 @@ pub struct SDL_PixelFormat {
   pub next: *mut SDL_PixelFormat,
 }
 
-extern "C" {
+sdl_extern! {
   /// Get the human readable name of a pixel format
   pub fn SDL_GetPixelFormatName(format: Uint32) -> *const c_char;
 
@@ -2,7 +2,7 @@
 
 use crate::c_char;
 
-extern "C" {
+sdl_extern! {
   /// Gets the name of the platform.
   pub fn SDL_GetPlatform() -> *const c_char;
 }
@@ -5,7 +5,7 @@ use crate::c_int;
 /// The basic state for the system's power supply.
 ///
 /// See the `SDL_POWERSTATE_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_PowerState(pub u32);
 
@@ -19,8 +19,15 @@ pub const SDL_POWERSTATE_NO_BATTERY: SDL_PowerState = SDL_PowerState(2);
 pub const SDL_POWERSTATE_CHARGING: SDL_PowerState = SDL_PowerState(3);
 /// Plugged in, battery charged.
 pub const SDL_POWERSTATE_CHARGED: SDL_PowerState = SDL_PowerState(4);
+impl_names_for_tuple_newtype!(SDL_PowerState {
+  SDL_POWERSTATE_UNKNOWN,
+  SDL_POWERSTATE_ON_BATTERY,
+  SDL_POWERSTATE_NO_BATTERY,
+  SDL_POWERSTATE_CHARGING,
+  SDL_POWERSTATE_CHARGED,
+});
 
-extern "C" {
+sdl_extern! {
   /// Get the current power supply details.
   ///
   /// * `secs` Seconds of battery life left. You can pass a NULL here if you
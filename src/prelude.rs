@@ -15,5 +15,23 @@ pub use crate::{
   stdinc::*, surface::*, syswm::*, timer::*, touch::*, version::*, video::*, *,
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::replay::*;
+
 #[cfg(feature = "vulkan")]
 pub use crate::vulkan::*;
+
+#[cfg(feature = "test_harness")]
+pub use crate::test_harness::*;
+
+#[cfg(feature = "image")]
+pub use crate::image::*;
+
+#[cfg(feature = "ttf")]
+pub use crate::ttf::*;
+
+#[cfg(feature = "mixer")]
+pub use crate::mixer::*;
+
+#[cfg(feature = "net")]
+pub use crate::net::*;
@@ -56,7 +56,59 @@ pub const fn SDL_RectEmpty(r: SDL_Rect) -> bool {
   (r.w <= 0) || (r.h <= 0)
 }
 
-extern "C" {
+/// Returns `true` if the two rectangles are equal.
+#[inline]
+#[must_use]
+pub const fn SDL_RectEquals(a: SDL_Rect, b: SDL_Rect) -> bool {
+  (a.x == b.x) && (a.y == b.y) && (a.w == b.w) && (a.h == b.h)
+}
+
+// Note: unlike the integer versions above, the floating versions can't be
+// `const fn` yet. Float math and comparisons in a `const fn` need Rust 1.82,
+// and the crate's MSRV is 1.64.
+
+/// Returns `true` if a point resides inside a rectangle (floating).
+#[inline]
+#[must_use]
+pub fn SDL_PointInFRect(p: SDL_FPoint, r: SDL_FRect) -> bool {
+  (p.x >= r.x) && (p.x < (r.x + r.w)) && (p.y >= r.y) && (p.y < (r.y + r.h))
+}
+
+/// Returns `true` if the rectangle has no area (floating).
+#[inline]
+#[must_use]
+pub fn SDL_FRectEmpty(r: SDL_FRect) -> bool {
+  (r.w <= 0.0) || (r.h <= 0.0)
+}
+
+/// Returns `true` if the two rectangles are equal, within some given epsilon.
+#[inline]
+#[must_use]
+pub fn SDL_FRectEqualsEpsilon(
+  a: SDL_FRect, b: SDL_FRect, epsilon: c_float,
+) -> bool {
+  fn fabsf(f: c_float) -> c_float {
+    if f < 0.0 {
+      -f
+    } else {
+      f
+    }
+  }
+  (fabsf(a.x - b.x) <= epsilon)
+    && (fabsf(a.y - b.y) <= epsilon)
+    && (fabsf(a.w - b.w) <= epsilon)
+    && (fabsf(a.h - b.h) <= epsilon)
+}
+
+/// Returns `true` if the two rectangles are equal, using [`f32::EPSILON`] as
+/// the epsilon.
+#[inline]
+#[must_use]
+pub fn SDL_FRectEquals(a: SDL_FRect, b: SDL_FRect) -> bool {
+  SDL_FRectEqualsEpsilon(a, b, c_float::EPSILON)
+}
+
+sdl_extern! {
   /// Determine whether two rectangles intersect.
   ///
   /// **Return:** `SDL_TRUE` if there is an intersection, `SDL_FALSE` otherwise.
@@ -91,4 +143,58 @@ extern "C" {
     rect: *const SDL_Rect, X1: *mut c_int, Y1: *mut c_int, X2: *mut c_int,
     Y2: *mut c_int,
   ) -> SDL_bool;
+
+  /// Determine whether two rectangles intersect (floating).
+  ///
+  /// **Return:** `SDL_TRUE` if there is an intersection, `SDL_FALSE` otherwise.
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_HasIntersectionF(
+    A: *const SDL_FRect, B: *const SDL_FRect,
+  ) -> SDL_bool;
+
+  /// Calculate the intersection of two rectangles (floating).
+  ///
+  /// **Return:** `SDL_TRUE` if there is an intersection, `SDL_FALSE` otherwise.
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_IntersectFRect(
+    A: *const SDL_FRect, B: *const SDL_FRect, result: *mut SDL_FRect,
+  ) -> SDL_bool;
+
+  /// Calculate the union of two rectangles (floating).
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_UnionFRect(
+    A: *const SDL_FRect, B: *const SDL_FRect, result: *mut SDL_FRect,
+  );
+
+  /// Calculate a minimal rectangle enclosing a set of points (floating).
+  ///
+  /// If `clip` is not null then only points inside of the clipping rectangle
+  /// are considered.
+  ///
+  /// **Return:** `SDL_TRUE` if any points were within the clipping rect
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_EncloseFPoints(
+    points: *const SDL_FPoint, count: c_int, clip: *const SDL_FRect,
+    result: *mut SDL_FRect,
+  ) -> SDL_bool;
+
+  /// Calculate the intersection of a rectangle and line segment (floating).
+  ///
+  /// A line segment contained entirely within the rectangle or that does not
+  /// intersect will remain unchanged. A line segment that crosses the
+  /// rectangle at either or both ends will be clipped to the boundary of the
+  /// rectangle and the new coordinates saved in `X1`, `Y1`, `X2`, and/or `Y2`
+  /// as necessary.
+  ///
+  /// **Return:** `SDL_TRUE` if there is an intersection, `SDL_FALSE` otherwise.
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_IntersectFRectAndLine(
+    rect: *const SDL_FRect, X1: *mut c_float, Y1: *mut c_float,
+    X2: *mut c_float, Y2: *mut c_float,
+  ) -> SDL_bool;
 }
@@ -20,15 +20,12 @@
 //! details: <http://bugzilla.libsdl.org/show_bug.cgi?id=1995>
 
 use crate::{
-  blendmode::*, c_char, c_int, c_void, rect::*, stdinc::*, surface::*, video::*,
+  blendmode::*, c_char, c_int, c_void, pixels::*, rect::*, stdinc::*,
+  surface::*, video::*,
 };
 
-// makes rustdoc link properly!
-#[allow(unused)]
-use crate::pixels::*;
-
 /// Flags used when creating a rendering context
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_RendererFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_RendererFlags);
@@ -44,6 +41,12 @@ pub const SDL_RENDERER_PRESENTVSYNC: SDL_RendererFlags =
 /// The renderer supports rendering to texture
 pub const SDL_RENDERER_TARGETTEXTURE: SDL_RendererFlags =
   SDL_RendererFlags(0x00000008);
+impl_names_for_tuple_newtype!(flags SDL_RendererFlags {
+  SDL_RENDERER_SOFTWARE,
+  SDL_RENDERER_ACCELERATED,
+  SDL_RENDERER_PRESENTVSYNC,
+  SDL_RENDERER_TARGETTEXTURE,
+});
 
 /// Information on the capabilities of a render driver or context.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -71,10 +74,22 @@ impl Default for SDL_RendererInfo {
   }
 }
 
+/// Vertex structure, used with [`SDL_RenderGeometry`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SDL_Vertex {
+  /// Vertex position, in [`SDL_Renderer`] coordinates
+  pub position: SDL_FPoint,
+  /// Vertex color
+  pub color: SDL_Color,
+  /// Normalized texture coordinates, if needed
+  pub tex_coord: SDL_FPoint,
+}
+
 /// The scaling mode for a texture.
 ///
 /// See `SDL_ScaleMode*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_ScaleMode(pub i32);
 /// nearest pixel sampling
@@ -83,11 +98,16 @@ pub const SDL_ScaleModeNearest: SDL_ScaleMode = SDL_ScaleMode(0);
 pub const SDL_ScaleModeLinear: SDL_ScaleMode = SDL_ScaleMode(1);
 /// anisotropic filtering
 pub const SDL_ScaleModeBest: SDL_ScaleMode = SDL_ScaleMode(2);
+impl_names_for_tuple_newtype!(SDL_ScaleMode {
+  SDL_ScaleModeNearest,
+  SDL_ScaleModeLinear,
+  SDL_ScaleModeBest,
+});
 
 /// The access pattern allowed for a texture.
 ///
 /// See `SDL_TEXTUREACCESS_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_TextureAccess(pub i32);
 /// Changes rarely, not lockable.
@@ -96,11 +116,16 @@ pub const SDL_TEXTUREACCESS_STATIC: SDL_TextureAccess = SDL_TextureAccess(0);
 pub const SDL_TEXTUREACCESS_STREAMING: SDL_TextureAccess = SDL_TextureAccess(1);
 /// Texture can be used as a render target.
 pub const SDL_TEXTUREACCESS_TARGET: SDL_TextureAccess = SDL_TextureAccess(2);
+impl_names_for_tuple_newtype!(SDL_TextureAccess {
+  SDL_TEXTUREACCESS_STATIC,
+  SDL_TEXTUREACCESS_STREAMING,
+  SDL_TEXTUREACCESS_TARGET,
+});
 
 /// The texture channel modulation used in [`SDL_RenderCopy`]
 ///
 /// See `SDL_TEXTUREMODULATE_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_TextureModulate(pub i32);
 /// No modulation.
@@ -112,11 +137,16 @@ pub const SDL_TEXTUREMODULATE_COLOR: SDL_TextureModulate =
 /// `srcA = srcA * alpha`
 pub const SDL_TEXTUREMODULATE_ALPHA: SDL_TextureModulate =
   SDL_TextureModulate(2);
+impl_names_for_tuple_newtype!(SDL_TextureModulate {
+  SDL_TEXTUREMODULATE_NONE,
+  SDL_TEXTUREMODULATE_COLOR,
+  SDL_TEXTUREMODULATE_ALPHA,
+});
 
 /// Flip values for [`SDL_RenderCopyEx`]
 ///
 /// See `SDL_FLIP_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_RendererFlip(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_RendererFlip);
@@ -126,6 +156,11 @@ pub const SDL_FLIP_NONE: SDL_RendererFlip = SDL_RendererFlip(0x00000000);
 pub const SDL_FLIP_HORIZONTAL: SDL_RendererFlip = SDL_RendererFlip(0x00000001);
 /// flip vertically
 pub const SDL_FLIP_VERTICAL: SDL_RendererFlip = SDL_RendererFlip(0x00000002);
+impl_names_for_tuple_newtype!(flags SDL_RendererFlip {
+  SDL_FLIP_NONE,
+  SDL_FLIP_HORIZONTAL,
+  SDL_FLIP_VERTICAL,
+});
 
 /// An opaque structure representing rendering state.
 #[derive(Debug)]
@@ -137,7 +172,7 @@ pub struct SDL_Renderer(c_void);
 #[repr(transparent)]
 pub struct SDL_Texture(c_void);
 
-extern "C" {
+sdl_extern! {
   /// Get the number of 2D rendering drivers available for the current display.
   ///
   /// A render driver is a set of code that handles rendering and texture
@@ -956,6 +991,64 @@ extern "C" {
     center: *const SDL_FPoint, flip: SDL_RendererFlip,
   ) -> c_int;
 
+  /// Render a list of triangles, optionally using a texture and indices into
+  /// the vertex array.
+  ///
+  /// Color and alpha modulation is done per vertex ([`SDL_SetTextureColorMod`]
+  /// and [`SDL_SetTextureAlphaMod`] are ignored).
+  ///
+  /// * `renderer` The rendering context.
+  /// * `texture` (optional) The SDL texture to use.
+  /// * `vertices` Vertices.
+  /// * `num_vertices` Number of vertices.
+  /// * `indices` (optional) An array of integer indices into the `vertices`
+  ///   array, if NULL all vertices will be rendered in sequential order.
+  /// * `num_indices` Number of indices.
+  ///
+  /// **Returns:** 0 on success, or -1 if the operation is not supported.
+  ///
+  /// See Also: [`SDL_RenderGeometryRaw`], [`SDL_Vertex`]
+  #[cfg(feature = "sdl_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_18")))]
+  pub fn SDL_RenderGeometry(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    vertices: *const SDL_Vertex, num_vertices: c_int, indices: *const c_int,
+    num_indices: c_int,
+  ) -> c_int;
+
+  /// Render a list of triangles, optionally using a texture and indices into
+  /// the vertex arrays.
+  ///
+  /// Color and alpha modulation is done per vertex ([`SDL_SetTextureColorMod`]
+  /// and [`SDL_SetTextureAlphaMod`] are ignored).
+  ///
+  /// * `renderer` The rendering context.
+  /// * `texture` (optional) The SDL texture to use.
+  /// * `xy` Vertex positions.
+  /// * `xy_stride` Byte size to move from one element to the next element.
+  /// * `color` Vertex colors (as [`SDL_Color`]).
+  /// * `color_stride` Byte size to move from one element to the next element.
+  /// * `uv` Vertex normalized texture coordinates.
+  /// * `uv_stride` Byte size to move from one element to the next element.
+  /// * `num_vertices` Number of vertices.
+  /// * `indices` (optional) An array of indices into the vertex arrays, if
+  ///   NULL all vertices will be rendered in sequential order.
+  /// * `num_indices` Number of indices.
+  /// * `size_indices` Index size: 1 (byte), 2 (short), 4 (int)
+  ///
+  /// **Returns:** 0 on success, or -1 if the operation is not supported.
+  ///
+  /// See Also: [`SDL_RenderGeometry`], [`SDL_Vertex`]
+  #[cfg(feature = "sdl_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_18")))]
+  pub fn SDL_RenderGeometryRaw(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    xy: *const crate::c_float, xy_stride: c_int, color: *const SDL_Color,
+    color_stride: c_int, uv: *const crate::c_float, uv_stride: c_int,
+    num_vertices: c_int, indices: *const c_void, num_indices: c_int,
+    size_indices: c_int,
+  ) -> c_int;
+
   /// Read pixels from the current rendering target.
   ///
   /// * `renderer` The renderer from which pixels should be read.
@@ -1067,6 +1160,8 @@ extern "C" {
   /// * `UVpitch` the number of bytes between rows of pixel data for the UV
   ///   plane.
   /// * **Returns:** 0 on success, or -1 if the texture is not valid.
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_UpdateNVTexture(
     texture: *mut SDL_Texture, rect: *const SDL_Rect, Yplane: *const Uint8,
     Ypitch: c_int, UVplane: *const Uint8, UVpitch: c_int,
@@ -0,0 +1,515 @@
+//! Recording a stream of events and replaying it later.
+//!
+//! An [`EventRecorder`] keeps a copy of each event you poll through it, and
+//! its bytes can be saved to a file. An [`EventPlayer`] loads those bytes and
+//! pushes the events back into the queue with [`SDL_PushEvent`], at the same
+//! pace they were recorded (or all at once). Together with the `dummy` video
+//! driver this makes input-driven tests repeatable, and it lets a player send
+//! in a recording of the input that caused a bug.
+//!
+//! Events that carry pointers ([`SDL_SysWMEvent`], [`SDL_DropEvent`], and all
+//! user events) can't be saved and are skipped. So are event types that
+//! fermium doesn't know about.
+//!
+//! ## Format
+//!
+//! A recording starts with the 8 byte magic `b"FERMEVTS"`, then the format
+//! version (currently 1), then a layout byte. After that each event is one
+//! length byte followed by that many bytes of the event's data, in memory
+//! order, with any trailing zero bytes cut off. Padding between the fields of
+//! an event is written as zero.
+//!
+//! The event data isn't converted in any way, so the layout byte records the
+//! byte order and the alignment of 64-bit values, and a player will refuse a
+//! recording made with a different layout.
+
+use alloc::vec::Vec;
+
+use crate::{c_int, events::*, timer::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::syswm::*;
+
+const MAGIC: [u8; 8] = *b"FERMEVTS";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = MAGIC.len() + 2;
+
+/// The byte order in the low bit, and the alignment of `i64` above that.
+const fn layout_byte() -> u8 {
+  (cfg!(target_endian = "big") as u8)
+    | ((core::mem::align_of::<i64>() as u8) << 1)
+}
+
+/// Copies a field of an event into `out`, at the same offset it has in the
+/// event.
+///
+/// ## Safety
+/// * `field` must be inside the value that `base` points to.
+/// * `T` must not have any padding bytes.
+unsafe fn put_field<T: Copy>(out: &mut [u8], base: *const u8, field: &T) {
+  let field = (field as *const T).cast::<u8>();
+  let offset = field.offset_from(base) as usize;
+  let bytes = core::slice::from_raw_parts(field, core::mem::size_of::<T>());
+  out[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+/// Copies each named field of the event struct `$e` into `$out`.
+///
+/// The event structs can have padding between or after their fields, which
+/// can't be read as bytes, so every field is listed out instead. Any field
+/// that's a struct itself is listed field by field too.
+macro_rules! put_fields {
+  ($out:ident, $e:ident: $($($field:ident).+),+ $(,)?) => {{
+    let base = core::ptr::addr_of!($e).cast::<u8>();
+    // Safety: the fields are all plain integers, floats, and arrays of them.
+    $( unsafe { put_field(&mut $out, base, &$e.$($field).+) }; )+
+    core::mem::size_of_val(&$e)
+  }};
+}
+
+/// The bytes of an event that a recording keeps, with any padding left as
+/// zero, and how many of them are in use by the event's type.
+///
+/// Gives `None` if the type can't be recorded.
+fn event_data(
+  event: &SDL_Event,
+) -> Option<([u8; core::mem::size_of::<SDL_Event>()], usize)> {
+  let mut out = [0_u8; core::mem::size_of::<SDL_Event>()];
+  let size = match event.decode() {
+    Event::Quit(e) => put_fields!(out, e: type_, timestamp),
+    Event::Common(e) => put_fields!(out, e: type_, timestamp),
+    Event::Display(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      display,
+      event,
+      padding1,
+      padding2,
+      padding3,
+      data1,
+    ),
+    Event::Window(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      windowID,
+      event,
+      padding1,
+      padding2,
+      padding3,
+      data1,
+      data2,
+    ),
+    Event::Keyboard(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      windowID,
+      state,
+      repeat,
+      padding2,
+      padding3,
+      keysym.scancode,
+      keysym.sym,
+      keysym.mod_,
+      keysym.unused,
+    ),
+    Event::TextEditing(e) => {
+      put_fields!(out, e: type_, timestamp, windowID, text, start, length)
+    }
+    Event::TextInput(e) => {
+      put_fields!(out, e: type_, timestamp, windowID, text)
+    }
+    Event::MouseMotion(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      windowID,
+      which,
+      state,
+      x,
+      y,
+      xrel,
+      yrel,
+    ),
+    Event::MouseButton(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      windowID,
+      which,
+      button,
+      state,
+      clicks,
+      padding1,
+      x,
+      y,
+    ),
+    Event::MouseWheel(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      windowID,
+      which,
+      x,
+      y,
+      direction,
+    ),
+    Event::JoyAxis(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      axis,
+      padding1,
+      padding2,
+      padding3,
+      value,
+      padding4,
+    ),
+    Event::JoyBall(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      ball,
+      padding1,
+      padding2,
+      padding3,
+      xrel,
+      yrel,
+    ),
+    Event::JoyHat(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      hat,
+      value,
+      padding1,
+      padding2,
+    ),
+    Event::JoyButton(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      button,
+      state,
+      padding1,
+      padding2,
+    ),
+    Event::JoyDevice(e) => put_fields!(out, e: type_, timestamp, which),
+    Event::ControllerAxis(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      axis,
+      padding1,
+      padding2,
+      padding3,
+      value,
+      padding4,
+    ),
+    Event::ControllerButton(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      button,
+      state,
+      padding1,
+      padding2,
+    ),
+    Event::ControllerDevice(e) => put_fields!(out, e: type_, timestamp, which),
+    Event::ControllerTouchpad(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      touchpad,
+      finger,
+      x,
+      y,
+      pressure,
+    ),
+    Event::ControllerSensor(e) => {
+      put_fields!(out, e: type_, timestamp, which, sensor, data)
+    }
+    Event::TouchFinger(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      touchId,
+      fingerId,
+      x,
+      y,
+      dx,
+      dy,
+      pressure,
+      windowID,
+    ),
+    Event::DollarGesture(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      touchId,
+      gestureId,
+      numFingers,
+      error,
+      x,
+      y,
+    ),
+    Event::MultiGesture(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      touchId,
+      dTheta,
+      dDist,
+      x,
+      y,
+      numFingers,
+      padding,
+    ),
+    Event::AudioDevice(e) => put_fields!(
+      out,
+      e: type_,
+      timestamp,
+      which,
+      iscapture,
+      padding1,
+      padding2,
+      padding3,
+    ),
+    Event::Sensor(e) => put_fields!(out, e: type_, timestamp, which, data),
+    Event::SysWM(_) | Event::Drop(_) | Event::User(_) | Event::Unknown(_) => {
+      return None
+    }
+  };
+  Some((out, size))
+}
+
+/// The number of bytes of an event's data that a recording keeps, or `None` if
+/// the type can't be recorded.
+fn recorded_size(type_: SDL_EventType) -> Option<usize> {
+  let mut event = SDL_Event::default();
+  event.type_ = type_;
+  event_data(&event).map(|(_, size)| size)
+}
+
+/// Records events into the replay format.
+///
+/// See the [module docs](self) for what can be recorded.
+#[derive(Debug, Clone)]
+pub struct EventRecorder {
+  bytes: Vec<u8>,
+}
+impl Default for EventRecorder {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+impl EventRecorder {
+  /// Makes a new, empty recording.
+  #[must_use]
+  pub fn new() -> Self {
+    let mut bytes = Vec::with_capacity(4096);
+    bytes.extend_from_slice(&MAGIC);
+    bytes.push(VERSION);
+    bytes.push(layout_byte());
+    Self { bytes }
+  }
+
+  /// Adds an event to the recording.
+  ///
+  /// **Returns:** if the event was recorded (`false` if it's a type that can't
+  /// be recorded).
+  pub fn record(&mut self, event: &SDL_Event) -> bool {
+    let (data, size) = match event_data(event) {
+      Some(data) => data,
+      None => return false,
+    };
+    let data = &data[..size];
+    let len = data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    self.bytes.push(len as u8);
+    self.bytes.extend_from_slice(&data[..len]);
+    true
+  }
+
+  /// Polls all the events in the queue with [`SDL_PollEvent`], recording each
+  /// one and then passing it along.
+  ///
+  /// ## Safety
+  /// * As [`poll_iter`].
+  pub unsafe fn poll_iter(&mut self) -> impl Iterator<Item = Event> + '_ {
+    core::iter::from_fn(move || {
+      let mut event = SDL_Event::default();
+      if SDL_PollEvent(&mut event) == 1 {
+        self.record(&event);
+        Some(event.decode())
+      } else {
+        None
+      }
+    })
+  }
+
+  /// The bytes of the recording so far.
+  #[inline]
+  #[must_use]
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  /// Finishes the recording, giving its bytes.
+  #[inline]
+  #[must_use]
+  pub fn into_bytes(self) -> Vec<u8> {
+    self.bytes
+  }
+}
+
+/// Plays back a recording made by an [`EventRecorder`].
+#[derive(Clone)]
+pub struct EventPlayer {
+  events: Vec<SDL_Event>,
+  next: usize,
+  start: Option<u32>,
+}
+impl core::fmt::Debug for EventPlayer {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("EventPlayer")
+      .field("len", &self.events.len())
+      .field("next", &self.next)
+      .finish()
+  }
+}
+impl EventPlayer {
+  /// Loads a recording.
+  ///
+  /// The events are sorted by timestamp, keeping the recorded order of events
+  /// with the same timestamp.
+  ///
+  /// **Returns:** the player, or `None` if the bytes aren't a recording, are
+  /// from another format version or layout, or are cut short.
+  #[must_use]
+  pub fn new(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() < HEADER_LEN {
+      return None;
+    }
+    let (header, mut rest) = bytes.split_at(HEADER_LEN);
+    if header[..MAGIC.len()] != MAGIC
+      || header[MAGIC.len()] != VERSION
+      || header[MAGIC.len() + 1] != layout_byte()
+    {
+      return None;
+    }
+    let mut events = Vec::new();
+    while let Some((&len, after)) = rest.split_first() {
+      let len = usize::from(len);
+      if after.len() < len {
+        return None;
+      }
+      let (data, after) = after.split_at(len);
+      if data.len() > core::mem::size_of::<SDL_Event>() {
+        return None;
+      }
+      let mut event = SDL_Event::default();
+      // Safety: the type is checked against `len` before the event is used.
+      unsafe {
+        core::slice::from_raw_parts_mut(
+          (&mut event as *mut SDL_Event).cast::<u8>(),
+          data.len(),
+        )
+      }
+      .copy_from_slice(data);
+      match recorded_size(unsafe { event.type_ }) {
+        Some(size) if data.len() <= size => (),
+        _ => return None,
+      }
+      events.push(event);
+      rest = after;
+    }
+    events.sort_by_key(|event| unsafe { event.common.timestamp });
+    Some(Self { events, next: 0, start: None })
+  }
+
+  /// The events of the recording, in the order they're played.
+  #[inline]
+  #[must_use]
+  pub fn events(&self) -> &[SDL_Event] {
+    &self.events
+  }
+
+  /// If all the events have been pushed.
+  #[inline]
+  #[must_use]
+  pub fn is_finished(&self) -> bool {
+    self.next == self.events.len()
+  }
+
+  /// Pushes each event that's due with [`SDL_PushEvent`].
+  ///
+  /// The first call starts the clock, and pushes the first event. After that
+  /// an event is due once as much time has passed (by [`SDL_GetTicks`]) as
+  /// passed between it and the first event in the recording. Call this once
+  /// per frame, before polling.
+  ///
+  /// SDL gives the pushed events new timestamps.
+  ///
+  /// **Returns:** the number of events pushed, or `Err` with the return code
+  /// of [`SDL_PushEvent`] if it fails (that event will be tried again next
+  /// time).
+  ///
+  /// ## Safety
+  /// * The events subsystem must be initialized.
+  pub unsafe fn push_due(&mut self) -> Result<usize, c_int> {
+    let now = SDL_GetTicks();
+    let start = *self.start.get_or_insert(now);
+    let elapsed = now.wrapping_sub(start);
+    let first = match self.events.first() {
+      Some(first) => first.common.timestamp,
+      None => return Ok(0),
+    };
+    let mut pushed = 0;
+    while let Some(event) = self.events.get(self.next) {
+      if event.common.timestamp.wrapping_sub(first) > elapsed {
+        break;
+      }
+      let mut copy = *event;
+      let r = SDL_PushEvent(&mut copy);
+      if r < 0 {
+        return Err(r);
+      }
+      self.next += 1;
+      pushed += 1;
+    }
+    Ok(pushed)
+  }
+
+  /// Pushes all of the remaining events right away with [`SDL_PushEvent`].
+  ///
+  /// The event queue holds a limited number of events, so for a long recording
+  /// you'll want to poll between calls to [`push_due`](Self::push_due)
+  /// instead.
+  ///
+  /// ## Safety
+  /// * As [`push_due`](Self::push_due).
+  pub unsafe fn push_all(&mut self) -> Result<usize, c_int> {
+    let mut pushed = 0;
+    while let Some(event) = self.events.get(self.next) {
+      let mut copy = *event;
+      let r = SDL_PushEvent(&mut copy);
+      if r < 0 {
+        return Err(r);
+      }
+      self.next += 1;
+      pushed += 1;
+    }
+    Ok(pushed)
+  }
+}
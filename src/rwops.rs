@@ -17,7 +17,7 @@ use crate::{c_char, c_void};
 #[repr(transparent)]
 pub struct SDL_RWops(c_void);
 
-extern "C" {
+sdl_extern! {
   /// Creates a [`SDL_RWops`] from a filename and mode string.
   ///
   /// * `file` supports unicode filenames if it's a utf8-encoded name given,
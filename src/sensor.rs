@@ -31,7 +31,7 @@ pub struct SDL_SensorID(pub Sint32);
 ///
 /// Hare are the additional Android sensors:
 /// <https://developer.android.com/reference/android/hardware/SensorEvent.html#values>
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_SensorType(pub i32);
 
@@ -43,6 +43,12 @@ pub const SDL_SENSOR_UNKNOWN: SDL_SensorType = SDL_SensorType(0);
 pub const SDL_SENSOR_ACCEL: SDL_SensorType = SDL_SensorType(1);
 /// Gyroscope
 pub const SDL_SENSOR_GYRO: SDL_SensorType = SDL_SensorType(2);
+impl_names_for_tuple_newtype!(SDL_SensorType {
+  SDL_SENSOR_INVALID,
+  SDL_SENSOR_UNKNOWN,
+  SDL_SENSOR_ACCEL,
+  SDL_SENSOR_GYRO,
+});
 
 /// Accelerometer sensor
 ///
@@ -66,7 +72,7 @@ pub const SDL_SENSOR_GYRO: SDL_SensorType = SDL_SensorType(2);
 /// See Also: [`SDL_GetDisplayOrientation`]
 pub const SDL_STANDARD_GRAVITY: c_float = 9.80665;
 
-extern "C" {
+sdl_extern! {
   /// Locking for multi-threaded access to the sensor API.
   ///
   /// If you are using the sensor API or handling events from multiple threads
@@ -165,6 +171,25 @@ extern "C" {
     sensor: *mut SDL_Sensor, data: *mut c_float, num_values: c_int,
   ) -> c_int;
 
+  /// Get the current state of an opened sensor with the timestamp of the last
+  /// update.
+  ///
+  /// The number of values and interpretation of the data is sensor dependent.
+  ///
+  /// * `sensor` The sensor to query
+  /// * `timestamp` A pointer filled with the timestamp in microseconds of the
+  ///   current sensor reading if available, or 0 if not
+  /// * `data` A pointer filled with the current sensor state
+  /// * `num_values` The number of values to write to data
+  ///
+  /// **Returns:** 0 or -1 if an error occurred.
+  #[cfg(feature = "sdl_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_26")))]
+  pub fn SDL_SensorGetDataWithTimestamp(
+    sensor: *mut SDL_Sensor, timestamp: *mut Uint64, data: *mut c_float,
+    num_values: c_int,
+  ) -> c_int;
+
   /// Close a sensor previously opened with [`SDL_SensorOpen`]
   pub fn SDL_SensorClose(sensor: *mut SDL_Sensor);
 
@@ -33,7 +33,7 @@ pub const SDL_FALSE: SDL_bool = SDL_bool::new(false);
 /// `true` value
 pub const SDL_TRUE: SDL_bool = SDL_bool::new(true);
 
-extern "C" {
+sdl_extern! {
   /// Works like [`malloc`](https://man7.org/linux/man-pages/man3/malloc.3.html)
   pub fn SDL_malloc(size: usize) -> *mut c_void;
 
@@ -79,3 +79,28 @@ pub type SDL_realloc_func =
 
 /// Works like [`free`](https://man7.org/linux/man-pages/man3/free.3p.html)
 pub type SDL_free_func = Option<unsafe extern "C" fn(mem: *mut c_void)>;
+
+/// Copies a C string into an owned `String`, replacing any invalid UTF-8.
+///
+/// A null pointer gives `None`.
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn string_from_c(
+  p: *const crate::c_char,
+) -> Option<alloc::string::String> {
+  if p.is_null() {
+    None
+  } else {
+    let s = core::ffi::CStr::from_ptr(p);
+    Some(alloc::string::String::from_utf8_lossy(s.to_bytes()).into_owned())
+  }
+}
+
+/// As [`string_from_c`], and then the pointer is passed to [`SDL_free`].
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn string_from_sdl_alloc(
+  p: *mut crate::c_char,
+) -> Option<alloc::string::String> {
+  let out = string_from_c(p);
+  SDL_free(p.cast());
+  out
+}
@@ -64,7 +64,7 @@ pub struct SDL_Surface {
 }
 
 /// The formula used for converting between YUV and RGB.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_YUV_CONVERSION_MODE(pub u32);
 /// Full range JPEG
@@ -79,6 +79,12 @@ pub const SDL_YUV_CONVERSION_BT709: SDL_YUV_CONVERSION_MODE =
 /// BT.601 for SD content, BT.709 for HD content
 pub const SDL_YUV_CONVERSION_AUTOMATIC: SDL_YUV_CONVERSION_MODE =
   SDL_YUV_CONVERSION_MODE(3);
+impl_names_for_tuple_newtype!(SDL_YUV_CONVERSION_MODE {
+  SDL_YUV_CONVERSION_JPEG,
+  SDL_YUV_CONVERSION_BT601,
+  SDL_YUV_CONVERSION_BT709,
+  SDL_YUV_CONVERSION_AUTOMATIC,
+});
 
 /// Given a file path to a BMP file, load and parse it into an [`SDL_Surface`]
 /// as one step.
@@ -101,7 +107,7 @@ pub unsafe fn SDL_SaveBMP(
   SDL_SaveBMP_RW(surface, SDL_RWFromFile(file, b"wb\0".as_ptr().cast()), 1)
 }
 
-extern "C" {
+sdl_extern! {
   /// Allocate a new RGB surface.
   ///
   /// If the depth is 4 or 8 bits, an empty palette is allocated for the
@@ -465,6 +471,8 @@ extern "C" {
   ) -> SDL_YUV_CONVERSION_MODE;
 
   /// Perform bilinear scaling between two surfaces of the same format, 32BPP.
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_SoftStretchLinear(
     src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
     dstrect: *const SDL_Rect,
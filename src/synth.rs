@@ -0,0 +1,266 @@
+//! Synthesizing input events, for tests.
+//!
+//! These fill in the event the way SDL would for real input, and push it
+//! with [`SDL_PushEvent`]. The window ID is taken from the window with
+//! keyboard (or mouse) focus, if there is one.
+//!
+//! Pushed events only go through the event queue, so they don't change the
+//! state reported by [`SDL_GetKeyboardState`], [`SDL_GetMouseState`], or
+//! [`SDL_GameControllerGetButton`].
+//!
+//! Each function returns the same as [`SDL_PushEvent`]: 1 on success, 0 if
+//! the event was filtered, or negative on error. Functions that push more than
+//! one event stop at the first one that isn't pushed.
+//!
+//! This module isn't part of the prelude, so use it as `synth::key_tap` and so
+//! on.
+
+use crate::{
+  c_int, events::*, gamecontroller::*, joystick::*, keyboard::*, mouse::*,
+  scancode::*, timer::*, video::*,
+};
+
+#[inline]
+unsafe fn window_id(window: *mut SDL_Window) -> u32 {
+  if window.is_null() {
+    0
+  } else {
+    SDL_GetWindowID(window)
+  }
+}
+
+#[inline]
+unsafe fn push(mut event: SDL_Event) -> c_int {
+  SDL_PushEvent(&mut event)
+}
+
+/// Runs each push in turn, stopping at the first that doesn't succeed.
+#[inline]
+unsafe fn push_each(events: &[SDL_Event]) -> c_int {
+  for &event in events {
+    let r = push(event);
+    if r != 1 {
+      return r;
+    }
+  }
+  1
+}
+
+unsafe fn key_event(type_: SDL_EventType, scancode: SDL_Scancode) -> SDL_Event {
+  let mut event = SDL_Event::default();
+  event.key = SDL_KeyboardEvent {
+    type_,
+    timestamp: SDL_GetTicks(),
+    windowID: window_id(SDL_GetKeyboardFocus()),
+    state: if type_ == SDL_KEYDOWN { SDL_PRESSED } else { SDL_RELEASED },
+    keysym: SDL_Keysym {
+      scancode,
+      sym: SDL_GetKeyFromScancode(scancode),
+      mod_: SDL_GetModState().0 as u16,
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  event
+}
+
+/// Pushes an [`SDL_KEYDOWN`] event for the key.
+///
+/// The keycode is looked up with [`SDL_GetKeyFromScancode`], and the
+/// modifiers with [`SDL_GetModState`].
+///
+/// ## Safety
+/// * The events subsystem must be initialized.
+#[inline]
+pub unsafe fn key_down(scancode: SDL_Scancode) -> c_int {
+  push(key_event(SDL_KEYDOWN, scancode))
+}
+
+/// Pushes an [`SDL_KEYUP`] event for the key.
+///
+/// ## Safety
+/// * As [`key_down`].
+#[inline]
+pub unsafe fn key_up(scancode: SDL_Scancode) -> c_int {
+  push(key_event(SDL_KEYUP, scancode))
+}
+
+/// Pushes an [`SDL_KEYDOWN`] and then an [`SDL_KEYUP`] event for the key.
+///
+/// This doesn't push any [`SDL_TEXTINPUT`] event, use [`type_text`] for that.
+///
+/// ## Safety
+/// * As [`key_down`].
+#[inline]
+pub unsafe fn key_tap(scancode: SDL_Scancode) -> c_int {
+  push_each(&[key_event(SDL_KEYDOWN, scancode), key_event(SDL_KEYUP, scancode)])
+}
+
+/// Pushes [`SDL_TEXTINPUT`] events for the text.
+///
+/// Each event holds up to 31 bytes of the text (plus the null terminator),
+/// and the text is only split between characters.
+///
+/// ## Safety
+/// * As [`key_down`].
+pub unsafe fn type_text(text: &str) -> c_int {
+  let window_id = window_id(SDL_GetKeyboardFocus());
+  let mut rest = text;
+  while !rest.is_empty() {
+    let mut len = rest.len().min(SDL_TEXTINPUTEVENT_TEXT_SIZE - 1);
+    while !rest.is_char_boundary(len) {
+      len -= 1;
+    }
+    let (chunk, after) = rest.split_at(len);
+    let mut event = SDL_Event::default();
+    event.text = SDL_TextInputEvent {
+      type_: SDL_TEXTINPUT,
+      timestamp: SDL_GetTicks(),
+      windowID: window_id,
+      ..Default::default()
+    };
+    for (dst, &src) in event.text.text.iter_mut().zip(chunk.as_bytes()) {
+      *dst = src as _;
+    }
+    let r = push(event);
+    if r != 1 {
+      return r;
+    }
+    rest = after;
+  }
+  1
+}
+
+unsafe fn mouse_button_event(
+  type_: SDL_EventType, button: u32, x: c_int, y: c_int,
+) -> SDL_Event {
+  let mut event = SDL_Event::default();
+  event.button = SDL_MouseButtonEvent {
+    type_,
+    timestamp: SDL_GetTicks(),
+    windowID: window_id(SDL_GetMouseFocus()),
+    which: 0,
+    button: button as u8,
+    state: if type_ == SDL_MOUSEBUTTONDOWN {
+      SDL_PRESSED
+    } else {
+      SDL_RELEASED
+    },
+    clicks: 1,
+    x,
+    y,
+    ..Default::default()
+  };
+  event
+}
+
+/// Pushes an [`SDL_MOUSEBUTTONDOWN`] event for the button (eg:
+/// [`SDL_BUTTON_LEFT`]) at the window position given.
+///
+/// ## Safety
+/// * As [`key_down`].
+#[inline]
+pub unsafe fn mouse_button_down(button: u32, x: c_int, y: c_int) -> c_int {
+  push(mouse_button_event(SDL_MOUSEBUTTONDOWN, button, x, y))
+}
+
+/// Pushes an [`SDL_MOUSEBUTTONUP`] event for the button at the window position
+/// given.
+///
+/// ## Safety
+/// * As [`key_down`].
+#[inline]
+pub unsafe fn mouse_button_up(button: u32, x: c_int, y: c_int) -> c_int {
+  push(mouse_button_event(SDL_MOUSEBUTTONUP, button, x, y))
+}
+
+/// Pushes an [`SDL_MOUSEBUTTONDOWN`] and then an [`SDL_MOUSEBUTTONUP`] event
+/// for the button at the window position given.
+///
+/// ## Safety
+/// * As [`key_down`].
+#[inline]
+pub unsafe fn mouse_click(button: u32, x: c_int, y: c_int) -> c_int {
+  push_each(&[
+    mouse_button_event(SDL_MOUSEBUTTONDOWN, button, x, y),
+    mouse_button_event(SDL_MOUSEBUTTONUP, button, x, y),
+  ])
+}
+
+/// Pushes an [`SDL_MOUSEWHEEL`] event.
+///
+/// Positive `y` scrolls away from the user, positive `x` scrolls right.
+///
+/// ## Safety
+/// * As [`key_down`].
+pub unsafe fn mouse_wheel(x: i32, y: i32) -> c_int {
+  let mut event = SDL_Event::default();
+  event.wheel = SDL_MouseWheelEvent {
+    type_: SDL_MOUSEWHEEL,
+    timestamp: SDL_GetTicks(),
+    windowID: window_id(SDL_GetMouseFocus()),
+    which: 0,
+    x,
+    y,
+    direction: SDL_MOUSEWHEEL_NORMAL,
+  };
+  push(event)
+}
+
+unsafe fn controller_button_event(
+  type_: SDL_EventType, which: SDL_JoystickID, button: SDL_GameControllerButton,
+) -> SDL_Event {
+  let mut event = SDL_Event::default();
+  event.cbutton = SDL_ControllerButtonEvent {
+    type_,
+    timestamp: SDL_GetTicks(),
+    which,
+    button: button.0 as u8,
+    state: if type_ == SDL_CONTROLLERBUTTONDOWN {
+      SDL_PRESSED
+    } else {
+      SDL_RELEASED
+    },
+    ..Default::default()
+  };
+  event
+}
+
+/// Pushes an [`SDL_CONTROLLERBUTTONDOWN`] event for the button, from the
+/// controller with the instance ID `which`.
+///
+/// ## Safety
+/// * The events subsystem must be initialized.
+#[inline]
+pub unsafe fn controller_button_down(
+  which: SDL_JoystickID, button: SDL_GameControllerButton,
+) -> c_int {
+  push(controller_button_event(SDL_CONTROLLERBUTTONDOWN, which, button))
+}
+
+/// Pushes an [`SDL_CONTROLLERBUTTONUP`] event for the button, from the
+/// controller with the instance ID `which`.
+///
+/// ## Safety
+/// * As [`controller_button_down`].
+#[inline]
+pub unsafe fn controller_button_up(
+  which: SDL_JoystickID, button: SDL_GameControllerButton,
+) -> c_int {
+  push(controller_button_event(SDL_CONTROLLERBUTTONUP, which, button))
+}
+
+/// Pushes an [`SDL_CONTROLLERBUTTONDOWN`] and then an
+/// [`SDL_CONTROLLERBUTTONUP`] event for the button.
+///
+/// ## Safety
+/// * As [`controller_button_down`].
+#[inline]
+pub unsafe fn controller_button_tap(
+  which: SDL_JoystickID, button: SDL_GameControllerButton,
+) -> c_int {
+  push_each(&[
+    controller_button_event(SDL_CONTROLLERBUTTONDOWN, which, button),
+    controller_button_event(SDL_CONTROLLERBUTTONUP, which, button),
+  ])
+}
@@ -8,7 +8,7 @@ use crate::{c_uint, c_ulong, c_void, stdinc::*, version::*, video::*};
 /// These are the various supported windowing subsystems.
 ///
 /// See `SDL_SYSWM_*` constants
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_SYSWM_TYPE(pub i32);
 
@@ -38,6 +38,21 @@ pub const SDL_SYSWM_VIVANTE: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(10);
 pub const SDL_SYSWM_OS2: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(11);
 #[allow(missing_docs)]
 pub const SDL_SYSWM_HAIKU: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(12);
+impl_names_for_tuple_newtype!(SDL_SYSWM_TYPE {
+  SDL_SYSWM_UNKNOWN,
+  SDL_SYSWM_WINDOWS,
+  SDL_SYSWM_X11,
+  SDL_SYSWM_DIRECTFB,
+  SDL_SYSWM_COCOA,
+  SDL_SYSWM_UIKIT,
+  SDL_SYSWM_WAYLAND,
+  SDL_SYSWM_MIR,
+  SDL_SYSWM_WINRT,
+  SDL_SYSWM_ANDROID,
+  SDL_SYSWM_VIVANTE,
+  SDL_SYSWM_OS2,
+  SDL_SYSWM_HAIKU,
+});
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -234,7 +249,7 @@ impl SDL_SysWMinfo {
   }
 }
 
-extern "C" {
+sdl_extern! {
   /// This function allows access to driver-dependent window information.
   ///
   /// * `window` The window about which information is being requested
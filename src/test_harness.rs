@@ -0,0 +1,573 @@
+//! SDL's own testing utilities, from the `SDL2_test` library.
+//!
+//! These are the helpers SDL uses for its own deterministic tests: a seeded
+//! fuzzer, a random number generator, CRC32 and MD5 checksums, an 8x8 debug
+//! font, the common command line argument handling of SDL's test programs, and
+//! a set of reference images to compare rendering results against.
+//!
+//! Requires the `test_harness` feature, which builds `SDL2_test` along with the
+//! bundled SDL2.
+
+use crate::{
+  c_char, c_double, c_float, c_int, c_uchar, c_uint, c_ulong, events::*,
+  rect::*, renderer::*, stdinc::*, surface::*, video::*, SDL_InitFlags,
+};
+
+/// Width (and height) of a character drawn with [`SDLTest_DrawCharacter`].
+pub const FONT_CHARACTER_SIZE: c_int = 8;
+/// Height of a line of text, including the spacing between lines.
+pub const FONT_LINE_HEIGHT: c_int = FONT_CHARACTER_SIZE + 2;
+
+/// The polynomial used by the CRC32 functions (Perl `String::CRC32`
+/// compatible).
+pub const CRC32_POLY: u32 = 0xEDB88320;
+
+/// Default width of the windows made by [`SDLTest_CommonInit`].
+pub const DEFAULT_WINDOW_WIDTH: c_int = 640;
+/// Default height of the windows made by [`SDLTest_CommonInit`].
+pub const DEFAULT_WINDOW_HEIGHT: c_int = 480;
+
+/// [`SDLTest_CommonState::verbose`] flag: log video info.
+pub const VERBOSE_VIDEO: Uint32 = 0x00000001;
+/// [`SDLTest_CommonState::verbose`] flag: log display modes.
+pub const VERBOSE_MODES: Uint32 = 0x00000002;
+/// [`SDLTest_CommonState::verbose`] flag: log renderer info.
+pub const VERBOSE_RENDER: Uint32 = 0x00000004;
+/// [`SDLTest_CommonState::verbose`] flag: log events.
+pub const VERBOSE_EVENT: Uint32 = 0x00000008;
+/// [`SDLTest_CommonState::verbose`] flag: log audio info.
+pub const VERBOSE_AUDIO: Uint32 = 0x00000010;
+/// [`SDLTest_CommonState::verbose`] flag: also log motion events.
+pub const VERBOSE_MOTION: Uint32 = 0x00000020;
+
+/// State of the random number generator.
+///
+/// Set it up with [`SDLTest_RandomInit`] or [`SDLTest_RandomInitTime`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDLTest_RandomContext {
+  pub a: c_uint,
+  pub x: c_uint,
+  pub c: c_uint,
+  pub ah: c_uint,
+  pub al: c_uint,
+}
+
+/// State of an MD5 (Message-Digest) computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SDLTest_Md5Context {
+  /// number of _bits_ handled mod 2^64
+  pub i: [c_ulong; 2],
+  /// scratch buffer
+  pub buf: [c_ulong; 4],
+  /// input buffer
+  pub in_: [c_uchar; 64],
+  /// actual digest after [`SDLTest_Md5Final`]
+  pub digest: [c_uchar; 16],
+}
+impl Default for SDLTest_Md5Context {
+  #[inline]
+  fn default() -> Self {
+    Self { i: [0; 2], buf: [0; 4], in_: [0; 64], digest: [0; 16] }
+  }
+}
+
+/// State for CRC32 (checksum) computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SDLTest_Crc32Context {
+  /// CRC table
+  pub crc32_table: [c_uint; 256],
+}
+impl Default for SDLTest_Crc32Context {
+  #[inline]
+  fn default() -> Self {
+    Self { crc32_table: [0; 256] }
+  }
+}
+
+/// Data used for multi-line text output.
+///
+/// See [`SDLTest_TextWindowCreate`]
+#[derive(Debug)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDLTest_TextWindow {
+  pub rect: SDL_Rect,
+  pub current: c_int,
+  pub numlines: c_int,
+  pub lines: *mut *mut c_char,
+}
+
+/// The settings of an SDL test program, mostly filled in from the command
+/// line.
+///
+/// Made with [`SDLTest_CommonCreateState`].
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDLTest_CommonState {
+  // SDL init flags
+  pub argv: *mut *mut c_char,
+  pub flags: SDL_InitFlags,
+  /// See the `VERBOSE_*` constants.
+  pub verbose: Uint32,
+
+  // Video info
+  pub videodriver: *const c_char,
+  pub display: c_int,
+  pub window_title: *const c_char,
+  pub window_icon: *const c_char,
+  pub window_flags: SDL_WindowFlags,
+  pub flash_on_focus_loss: SDL_bool,
+  pub window_x: c_int,
+  pub window_y: c_int,
+  pub window_w: c_int,
+  pub window_h: c_int,
+  pub window_minW: c_int,
+  pub window_minH: c_int,
+  pub window_maxW: c_int,
+  pub window_maxH: c_int,
+  pub logical_w: c_int,
+  pub logical_h: c_int,
+  pub scale: c_float,
+  pub depth: c_int,
+  pub refresh_rate: c_int,
+  pub num_windows: c_int,
+  pub windows: *mut *mut SDL_Window,
+
+  // Renderer info
+  pub renderdriver: *const c_char,
+  pub render_flags: SDL_RendererFlags,
+  pub skip_renderer: SDL_bool,
+  pub renderers: *mut *mut SDL_Renderer,
+  pub targets: *mut *mut SDL_Texture,
+
+  // Audio info
+  pub audiodriver: *const c_char,
+  pub audiospec: crate::audio::SDL_AudioSpec,
+
+  // GL settings
+  pub gl_red_size: c_int,
+  pub gl_green_size: c_int,
+  pub gl_blue_size: c_int,
+  pub gl_alpha_size: c_int,
+  pub gl_buffer_size: c_int,
+  pub gl_depth_size: c_int,
+  pub gl_stencil_size: c_int,
+  pub gl_double_buffer: c_int,
+  pub gl_accum_red_size: c_int,
+  pub gl_accum_green_size: c_int,
+  pub gl_accum_blue_size: c_int,
+  pub gl_accum_alpha_size: c_int,
+  pub gl_stereo: c_int,
+  pub gl_multisamplebuffers: c_int,
+  pub gl_multisamplesamples: c_int,
+  pub gl_retained_backing: c_int,
+  pub gl_accelerated: c_int,
+  pub gl_major_version: c_int,
+  pub gl_minor_version: c_int,
+  pub gl_debug: c_int,
+  pub gl_profile_mask: c_int,
+
+  // Additional fields added in 2.0.18
+  pub confine: SDL_Rect,
+}
+
+sdl_extern! {
+  /// Initializes the fuzzer for a test.
+  ///
+  /// * `execKey` Execution "Key" that initializes the random number generator
+  ///   uniquely for the test.
+  ///
+  /// The fuzzer uses a single static random context internally, so none of the
+  /// fuzzer functions are thread safe.
+  pub fn SDLTest_FuzzerInit(execKey: Uint64);
+
+  /// Returns a random `Uint8`.
+  pub fn SDLTest_RandomUint8() -> Uint8;
+
+  /// Returns a random `Sint8`.
+  pub fn SDLTest_RandomSint8() -> Sint8;
+
+  /// Returns a random `Uint16`.
+  pub fn SDLTest_RandomUint16() -> Uint16;
+
+  /// Returns a random `Sint16`.
+  pub fn SDLTest_RandomSint16() -> Sint16;
+
+  /// Returns a random `Sint32`.
+  pub fn SDLTest_RandomSint32() -> Sint32;
+
+  /// Returns a random `Uint32`.
+  pub fn SDLTest_RandomUint32() -> Uint32;
+
+  /// Returns a random `Uint64`.
+  pub fn SDLTest_RandomUint64() -> Uint64;
+
+  /// Returns a random `Sint64`.
+  pub fn SDLTest_RandomSint64() -> Sint64;
+
+  /// Returns a random float in the range `[0.0, 1.0]`.
+  pub fn SDLTest_RandomUnitFloat() -> c_float;
+
+  /// Returns a random double in the range `[0.0, 1.0]`.
+  pub fn SDLTest_RandomUnitDouble() -> c_double;
+
+  /// Returns a random float.
+  pub fn SDLTest_RandomFloat() -> c_float;
+
+  /// Returns a random double.
+  pub fn SDLTest_RandomDouble() -> c_double;
+
+  /// Returns a random boundary value for `Uint8` within the given boundaries.
+  ///
+  /// Boundaries are inclusive, see the usage examples below. If `validDomain`
+  /// is true, the function will only return valid boundaries, otherwise
+  /// non-valid boundaries are also possible. If `boundary1 > boundary2`, the
+  /// values are swapped.
+  ///
+  /// ```txt
+  /// RandomUint8BoundaryValue(10, 20, SDL_TRUE) returns 10, 11, 19 or 20
+  /// RandomUint8BoundaryValue(1, 20, SDL_FALSE) returns 0 or 21
+  /// RandomUint8BoundaryValue(0, 99, SDL_FALSE) returns 100
+  /// RandomUint8BoundaryValue(0, 255, SDL_FALSE) returns 0 (error set)
+  /// ```
+  ///
+  /// **Returns:** a random boundary value for the given range and domain, or 0
+  /// with the error set.
+  ///
+  /// The other `Random*BoundaryValue` functions all work the same way. For the
+  /// signed types the error value is the type's minimum instead of 0.
+  pub fn SDLTest_RandomUint8BoundaryValue(
+    boundary1: Uint8, boundary2: Uint8, validDomain: SDL_bool,
+  ) -> Uint8;
+
+  /// Returns a random boundary value for `Uint16` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomUint16BoundaryValue(
+    boundary1: Uint16, boundary2: Uint16, validDomain: SDL_bool,
+  ) -> Uint16;
+
+  /// Returns a random boundary value for `Uint32` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomUint32BoundaryValue(
+    boundary1: Uint32, boundary2: Uint32, validDomain: SDL_bool,
+  ) -> Uint32;
+
+  /// Returns a random boundary value for `Uint64` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomUint64BoundaryValue(
+    boundary1: Uint64, boundary2: Uint64, validDomain: SDL_bool,
+  ) -> Uint64;
+
+  /// Returns a random boundary value for `Sint8` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomSint8BoundaryValue(
+    boundary1: Sint8, boundary2: Sint8, validDomain: SDL_bool,
+  ) -> Sint8;
+
+  /// Returns a random boundary value for `Sint16` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomSint16BoundaryValue(
+    boundary1: Sint16, boundary2: Sint16, validDomain: SDL_bool,
+  ) -> Sint16;
+
+  /// Returns a random boundary value for `Sint32` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomSint32BoundaryValue(
+    boundary1: Sint32, boundary2: Sint32, validDomain: SDL_bool,
+  ) -> Sint32;
+
+  /// Returns a random boundary value for `Sint64` within the given boundaries.
+  ///
+  /// See [`SDLTest_RandomUint8BoundaryValue`]
+  pub fn SDLTest_RandomSint64BoundaryValue(
+    boundary1: Sint64, boundary2: Sint64, validDomain: SDL_bool,
+  ) -> Sint64;
+
+  /// Returns an integer in the range `[min, max]` (inclusive).
+  ///
+  /// If `max` is smaller than `min` the values are swapped.
+  pub fn SDLTest_RandomIntegerInRange(min: Sint32, max: Sint32) -> Sint32;
+
+  /// Generates a random null-terminated string of 1 to 255 characters, using
+  /// ASCII characters from 32 to 126.
+  ///
+  /// **Returns:** a newly allocated string (free it with [`SDL_free`]), or
+  /// null on failure.
+  pub fn SDLTest_RandomAsciiString() -> *mut c_char;
+
+  /// Generates a random null-terminated string of up to `maxLength`
+  /// characters, using ASCII characters from 32 to 126.
+  ///
+  /// **Returns:** a newly allocated string (free it with [`SDL_free`]), or
+  /// null if `maxLength` was invalid or the allocation failed.
+  pub fn SDLTest_RandomAsciiStringWithMaximumLength(
+    maxLength: c_int,
+  ) -> *mut c_char;
+
+  /// Generates a random null-terminated string of exactly `size` characters,
+  /// using ASCII characters from 32 to 126.
+  ///
+  /// **Returns:** a newly allocated string (free it with [`SDL_free`]), or
+  /// null if `size` was invalid or the allocation failed.
+  pub fn SDLTest_RandomAsciiStringOfSize(size: c_int) -> *mut c_char;
+
+  /// Gets the number of fuzzer calls since the last [`SDLTest_FuzzerInit`].
+  pub fn SDLTest_GetFuzzerInvocationCount() -> c_int;
+
+  /// Initialize a random number generator with two integers.
+  ///
+  /// The sequence of numbers given by [`SDLTest_Random`] is the same for the
+  /// same two integers, and has a period of 2^31.
+  pub fn SDLTest_RandomInit(
+    rndContext: *mut SDLTest_RandomContext, xi: c_uint, ci: c_uint,
+  );
+
+  /// Initialize a random number generator based on the current system time.
+  pub fn SDLTest_RandomInitTime(rndContext: *mut SDLTest_RandomContext);
+
+  /// Gets the next random number from a generator.
+  ///
+  /// [`SDLTest_RandomInit`] or [`SDLTest_RandomInitTime`] must have been
+  /// called on the context first.
+  pub fn SDLTest_Random(rndContext: *mut SDLTest_RandomContext) -> c_uint;
+
+  /// Initialize the CRC context (this builds the CRC table).
+  ///
+  /// **Returns:** 0 for OK, -1 on error.
+  pub fn SDLTest_Crc32Init(crcContext: *mut SDLTest_Crc32Context) -> c_int;
+
+  /// Calculate a CRC32 of a data block.
+  ///
+  /// * `crcContext` an initialized context.
+  /// * `inBuf` the input buffer.
+  /// * `inLen` length of the input buffer.
+  /// * `crc32` gets the CRC.
+  ///
+  /// **Returns:** 0 for OK, -1 on error.
+  pub fn SDLTest_Crc32Calc(
+    crcContext: *mut SDLTest_Crc32Context, inBuf: *mut c_uchar, inLen: c_uint,
+    crc32: *mut c_uint,
+  ) -> c_int;
+
+  /// The first step of [`SDLTest_Crc32Calc`] done separately.
+  pub fn SDLTest_Crc32CalcStart(
+    crcContext: *mut SDLTest_Crc32Context, crc32: *mut c_uint,
+  ) -> c_int;
+
+  /// The last step of [`SDLTest_Crc32Calc`] done separately.
+  pub fn SDLTest_Crc32CalcEnd(
+    crcContext: *mut SDLTest_Crc32Context, crc32: *mut c_uint,
+  ) -> c_int;
+
+  /// The middle step of [`SDLTest_Crc32Calc`] done separately, this can be
+  /// called any number of times.
+  pub fn SDLTest_Crc32CalcBuffer(
+    crcContext: *mut SDLTest_Crc32Context, inBuf: *mut c_uchar, inLen: c_uint,
+    crc32: *mut c_uint,
+  ) -> c_int;
+
+  /// Clean up a CRC context.
+  ///
+  /// **Returns:** 0 for OK, -1 on error.
+  pub fn SDLTest_Crc32Done(crcContext: *mut SDLTest_Crc32Context) -> c_int;
+
+  /// Initialize an MD5 context. Call this before each new use of the context.
+  pub fn SDLTest_Md5Init(mdContext: *mut SDLTest_Md5Context);
+
+  /// Update the digest with some more data.
+  pub fn SDLTest_Md5Update(
+    mdContext: *mut SDLTest_Md5Context, inBuf: *mut c_uchar, inLen: c_uint,
+  );
+
+  /// Complete the digest computation.
+  ///
+  /// Afterwards the digest is in the `digest` field of the context.
+  pub fn SDLTest_Md5Final(mdContext: *mut SDLTest_Md5Context);
+
+  /// Draw a single character with the debug font.
+  ///
+  /// * `x` and `y` are the upper left corner of the character.
+  /// * `c` is the character's Unicode code point.
+  ///
+  /// **Returns:** 0 on success, -1 on failure.
+  pub fn SDLTest_DrawCharacter(
+    renderer: *mut SDL_Renderer, x: c_int, y: c_int, c: Uint32,
+  ) -> c_int;
+
+  /// Draw a UTF-8 string with the debug font.
+  ///
+  /// The font only supports characters in the Basic Latin and Latin-1
+  /// Supplement sets. Each character is [`FONT_CHARACTER_SIZE`] pixels square.
+  ///
+  /// * `x` and `y` are the upper left corner of the string.
+  ///
+  /// **Returns:** 0 on success, -1 on failure.
+  pub fn SDLTest_DrawString(
+    renderer: *mut SDL_Renderer, x: c_int, y: c_int, s: *const c_char,
+  ) -> c_int;
+
+  /// Create a multi-line text output window.
+  ///
+  /// The `w` and `h` are currently ignored.
+  ///
+  /// **Returns:** the new window, or null on failure.
+  pub fn SDLTest_TextWindowCreate(
+    x: c_int, y: c_int, w: c_int, h: c_int,
+  ) -> *mut SDLTest_TextWindow;
+
+  /// Draw a multi-line text output window, this should be called every frame.
+  pub fn SDLTest_TextWindowDisplay(
+    textwin: *mut SDLTest_TextWindow, renderer: *mut SDL_Renderer,
+  );
+
+  // Note: `SDLTest_TextWindowAddText` is skipped because it's variadic.
+
+  /// Add UTF-8 text to the end of a multi-line text output window.
+  ///
+  /// The newline character starts a new line of text. The backspace character
+  /// deletes the last character or, if the line is empty, deletes the line and
+  /// goes to the end of the previous line.
+  pub fn SDLTest_TextWindowAddTextWithLength(
+    textwin: *mut SDLTest_TextWindow, text: *const c_char, len: usize,
+  );
+
+  /// Clear the text of a multi-line text output window.
+  pub fn SDLTest_TextWindowClear(textwin: *mut SDLTest_TextWindow);
+
+  /// Free a multi-line text output window.
+  pub fn SDLTest_TextWindowDestroy(textwin: *mut SDLTest_TextWindow);
+
+  /// Cleanup the textures used by the font drawing functions.
+  pub fn SDLTest_CleanupTextDrawing();
+
+  /// Parse command line parameters and create the common state.
+  ///
+  /// * `argv` the null-terminated array of arguments.
+  /// * `flags` the [`SDL_InitFlags`] of the subsystems to use.
+  ///
+  /// **Returns:** the new state, or null on failure.
+  pub fn SDLTest_CommonCreateState(
+    argv: *mut *mut c_char, flags: SDL_InitFlags,
+  ) -> *mut SDLTest_CommonState;
+
+  /// Process one common argument.
+  ///
+  /// * `index` is the index of the argument to process in `argv`.
+  ///
+  /// **Returns:** the number of arguments processed (eg: 1 for
+  /// `--fullscreen`, 2 for `--video [videodriver]`), or -1 on error.
+  pub fn SDLTest_CommonArg(
+    state: *mut SDLTest_CommonState, index: c_int,
+  ) -> c_int;
+
+  /// Logs command line usage info, through `SDL_Log`.
+  ///
+  /// * `argv0` is `argv[0]`.
+  /// * `options` is a null-terminated array of application specific options
+  ///   to also list.
+  pub fn SDLTest_CommonLogUsage(
+    state: *mut SDLTest_CommonState, argv0: *const c_char,
+    options: *mut *const c_char,
+  );
+
+  /// Gets the common usage information as a string.
+  ///
+  /// You should probably use [`SDLTest_CommonLogUsage`] instead. The string is
+  /// freed by [`SDLTest_CommonQuit`].
+  pub fn SDLTest_CommonUsage(state: *mut SDLTest_CommonState) -> *const c_char;
+
+  /// Open the test window(s), and initialize the rest of the requested
+  /// subsystems.
+  ///
+  /// **Returns:** `SDL_TRUE` on success.
+  pub fn SDLTest_CommonInit(state: *mut SDLTest_CommonState) -> SDL_bool;
+
+  /// Easy argument handling when the test app doesn't need any custom args.
+  ///
+  /// **Returns:** `SDL_FALSE` if the app should quit.
+  pub fn SDLTest_CommonDefaultArgs(
+    state: *mut SDLTest_CommonState, argc: c_int, argv: *mut *mut c_char,
+  ) -> SDL_bool;
+
+  /// The common event handler for test windows.
+  ///
+  /// * `done` is set to non-zero when the app should quit.
+  pub fn SDLTest_CommonEvent(
+    state: *mut SDLTest_CommonState, event: *mut SDL_Event, done: *mut c_int,
+  );
+
+  /// Close the test window(s), and free the state.
+  pub fn SDLTest_CommonQuit(state: *mut SDLTest_CommonState);
+
+  /// Draws various window information (position, size, etc.) to the renderer.
+  ///
+  /// * `usedHeight` gets the height used, so you can draw more below it.
+  pub fn SDLTest_CommonDrawWindowInfo(
+    renderer: *mut SDL_Renderer, window: *mut SDL_Window,
+    usedHeight: *mut c_int,
+  );
+
+  /// Reference image for blitting. Free it with [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlit() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with a color mod. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitColor() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with an alpha mod. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitAlpha() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with additive blending. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitBlendAdd() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with alpha blending. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitBlend() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with modulate blending. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitBlendMod() -> *mut SDL_Surface;
+
+  /// Reference image for blitting without blending. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitBlendNone() -> *mut SDL_Surface;
+
+  /// Reference image for blitting with all the blend modes. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageBlitBlendAll() -> *mut SDL_Surface;
+
+  /// Reference image of a face. Free it with [`SDL_FreeSurface`].
+  pub fn SDLTest_ImageFace() -> *mut SDL_Surface;
+
+  /// Reference image for drawing primitives. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImagePrimitives() -> *mut SDL_Surface;
+
+  /// Reference image for drawing primitives with blending. Free it with
+  /// [`SDL_FreeSurface`].
+  pub fn SDLTest_ImagePrimitivesBlend() -> *mut SDL_Surface;
+
+  /// Compares a surface with a reference surface.
+  ///
+  /// * `allowable_error` the allowed difference (the sum of the squared
+  ///   difference of each RGB component) per pixel.
+  ///
+  /// **Returns:** 0 if they match, more than 0 for the number of pixels that
+  /// didn't match, -1 if either surface is null, or -2 if their sizes differ.
+  pub fn SDLTest_CompareSurfaces(
+    surface: *mut SDL_Surface, referenceSurface: *mut SDL_Surface,
+    allowable_error: c_int,
+  ) -> c_int;
+}
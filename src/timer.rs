@@ -16,7 +16,7 @@ pub type SDL_TimerCallback =
 #[repr(transparent)]
 pub struct SDL_TimerID(pub c_int);
 
-extern "C" {
+sdl_extern! {
   /// Get the number of milliseconds since the SDL library initialization.
   ///
   /// **Note:** This value wraps if the program runs for more than ~49 days.
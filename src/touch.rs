@@ -15,7 +15,7 @@ pub struct SDL_FingerID(pub Sint64);
 /// The types of a touch device.
 ///
 /// See `SDL_TOUCH_DEVICE_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_TouchDeviceType(pub i32);
 
@@ -30,6 +30,12 @@ pub const SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE: SDL_TouchDeviceType =
 /// Trackpad with screen cursor-relative coordinates
 pub const SDL_TOUCH_DEVICE_INDIRECT_RELATIVE: SDL_TouchDeviceType =
   SDL_TouchDeviceType(2);
+impl_names_for_tuple_newtype!(SDL_TouchDeviceType {
+  SDL_TOUCH_DEVICE_INVALID,
+  SDL_TOUCH_DEVICE_DIRECT,
+  SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE,
+  SDL_TOUCH_DEVICE_INDIRECT_RELATIVE,
+});
 
 /// Info for a finger object.
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
@@ -48,13 +54,19 @@ pub const SDL_TOUCH_MOUSEID: u32 = -1_i32 as u32;
 /// Used as the `SDL_TouchID` for *touch events* simulated with mouse input.
 pub const SDL_MOUSE_TOUCHID: SDL_TouchID = SDL_TouchID(-1);
 
-extern "C" {
+sdl_extern! {
   /// Get the number of registered touch devices.
-  pub fn SDL_GetNumTouchDevices();
+  pub fn SDL_GetNumTouchDevices() -> c_int;
 
   /// Get the touch ID with the given index, or 0 if the index is invalid.
   pub fn SDL_GetTouchDevice(index: c_int) -> SDL_TouchID;
 
+  /// Get the touch device name as reported from the driver, or NULL if the
+  /// index is invalid.
+  #[cfg(feature = "sdl_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_22")))]
+  pub fn SDL_GetTouchName(index: c_int) -> *const crate::c_char;
+
   /// Get the type of the given touch device.
   pub fn SDL_GetTouchDeviceType(touchID: SDL_TouchID) -> SDL_TouchDeviceType;
 
@@ -0,0 +1,323 @@
+//! Drawing text with TrueType fonts, using the `SDL2_ttf` library.
+//!
+//! Requires the `ttf` feature, and `SDL2_ttf` (2.20 or later) must be
+//! installed.
+//!
+//! All the text is UTF-8, and all the `TTF_Render*` functions give a new
+//! surface which should be freed with [`SDL_FreeSurface`]. Errors from these
+//! functions are reported through [`SDL_GetErrorMsg`].
+
+use crate::{
+  c_char, c_int, c_long, c_void, pixels::*, rwops::*, stdinc::*, surface::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// An opaque structure for a loaded font.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct TTF_Font(c_void);
+
+/// Font style flags, for [`TTF_SetFontStyle`].
+///
+/// See `TTF_STYLE_*` constants.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TTF_FontStyle(pub c_int);
+impl_bit_ops_for_tuple_newtype!(TTF_FontStyle);
+#[allow(missing_docs)]
+pub const TTF_STYLE_NORMAL: TTF_FontStyle = TTF_FontStyle(0x00);
+#[allow(missing_docs)]
+pub const TTF_STYLE_BOLD: TTF_FontStyle = TTF_FontStyle(0x01);
+#[allow(missing_docs)]
+pub const TTF_STYLE_ITALIC: TTF_FontStyle = TTF_FontStyle(0x02);
+#[allow(missing_docs)]
+pub const TTF_STYLE_UNDERLINE: TTF_FontStyle = TTF_FontStyle(0x04);
+#[allow(missing_docs)]
+pub const TTF_STYLE_STRIKETHROUGH: TTF_FontStyle = TTF_FontStyle(0x08);
+impl_names_for_tuple_newtype!(flags TTF_FontStyle {
+  TTF_STYLE_NORMAL,
+  TTF_STYLE_BOLD,
+  TTF_STYLE_ITALIC,
+  TTF_STYLE_UNDERLINE,
+  TTF_STYLE_STRIKETHROUGH,
+});
+
+/// Font hinting, for [`TTF_SetFontHinting`].
+///
+/// See `TTF_HINTING_*` constants.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TTF_Hinting(pub c_int);
+#[allow(missing_docs)]
+pub const TTF_HINTING_NORMAL: TTF_Hinting = TTF_Hinting(0);
+#[allow(missing_docs)]
+pub const TTF_HINTING_LIGHT: TTF_Hinting = TTF_Hinting(1);
+#[allow(missing_docs)]
+pub const TTF_HINTING_MONO: TTF_Hinting = TTF_Hinting(2);
+#[allow(missing_docs)]
+pub const TTF_HINTING_NONE: TTF_Hinting = TTF_Hinting(3);
+#[allow(missing_docs)]
+pub const TTF_HINTING_LIGHT_SUBPIXEL: TTF_Hinting = TTF_Hinting(4);
+impl_names_for_tuple_newtype!(TTF_Hinting {
+  TTF_HINTING_NORMAL,
+  TTF_HINTING_LIGHT,
+  TTF_HINTING_MONO,
+  TTF_HINTING_NONE,
+  TTF_HINTING_LIGHT_SUBPIXEL,
+});
+
+/// How the lines of wrapped text are aligned, for
+/// [`TTF_SetFontWrappedAlign`].
+///
+/// See `TTF_WRAPPED_ALIGN_*` constants.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TTF_WrappedAlign(pub c_int);
+#[allow(missing_docs)]
+pub const TTF_WRAPPED_ALIGN_LEFT: TTF_WrappedAlign = TTF_WrappedAlign(0);
+#[allow(missing_docs)]
+pub const TTF_WRAPPED_ALIGN_CENTER: TTF_WrappedAlign = TTF_WrappedAlign(1);
+#[allow(missing_docs)]
+pub const TTF_WRAPPED_ALIGN_RIGHT: TTF_WrappedAlign = TTF_WrappedAlign(2);
+impl_names_for_tuple_newtype!(TTF_WrappedAlign {
+  TTF_WRAPPED_ALIGN_LEFT,
+  TTF_WRAPPED_ALIGN_CENTER,
+  TTF_WRAPPED_ALIGN_RIGHT,
+});
+
+sdl_extern! {
+  /// Initializes the library.
+  ///
+  /// This must be called before any other function (except
+  /// [`TTF_WasInit`]). Each call must be balanced by a call to [`TTF_Quit`].
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_Init() -> c_int;
+
+  /// Shuts down the library (once every [`TTF_Init`] has been balanced).
+  ///
+  /// Close all fonts before calling this.
+  pub fn TTF_Quit();
+
+  /// **Returns:** how many times [`TTF_Init`] has been called without a
+  /// matching [`TTF_Quit`].
+  pub fn TTF_WasInit() -> c_int;
+
+  /// Opens a font file at a point size (based on 72 DPI).
+  ///
+  /// The font should be closed with [`TTF_CloseFont`].
+  ///
+  /// **Returns:** the new font, or NULL if there was an error.
+  pub fn TTF_OpenFont(file: *const c_char, ptsize: c_int) -> *mut TTF_Font;
+
+  /// As [`TTF_OpenFont`], but picks which face of a multi-face font file to
+  /// use (starting from 0).
+  pub fn TTF_OpenFontIndex(
+    file: *const c_char, ptsize: c_int, index: c_long,
+  ) -> *mut TTF_Font;
+
+  /// Opens a font from a seekable SDL data stream at a point size.
+  ///
+  /// If `freesrc` is non-zero, the stream will be closed when the font is
+  /// closed (or now, if there's an error). Otherwise the stream must stay open
+  /// until the font is closed.
+  ///
+  /// **Returns:** the new font, or NULL if there was an error.
+  pub fn TTF_OpenFontRW(
+    src: *mut SDL_RWops, freesrc: c_int, ptsize: c_int,
+  ) -> *mut TTF_Font;
+
+  /// Closes a font.
+  pub fn TTF_CloseFont(font: *mut TTF_Font);
+
+  /// Changes the point size of an open font.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SetFontSize(font: *mut TTF_Font, ptsize: c_int) -> c_int;
+
+  /// Gets the style of the font.
+  pub fn TTF_GetFontStyle(font: *const TTF_Font) -> TTF_FontStyle;
+
+  /// Sets the style of the font.
+  ///
+  /// This clears the glyph cache, even if the style is the same.
+  pub fn TTF_SetFontStyle(font: *mut TTF_Font, style: TTF_FontStyle);
+
+  /// Gets the outline size of the font in pixels (0 means no outline).
+  pub fn TTF_GetFontOutline(font: *const TTF_Font) -> c_int;
+
+  /// Sets the outline size of the font in pixels (0 means no outline).
+  pub fn TTF_SetFontOutline(font: *mut TTF_Font, outline: c_int);
+
+  /// Gets the hinting of the font.
+  pub fn TTF_GetFontHinting(font: *const TTF_Font) -> TTF_Hinting;
+
+  /// Sets the hinting of the font.
+  pub fn TTF_SetFontHinting(font: *mut TTF_Font, hinting: TTF_Hinting);
+
+  /// Gets if kerning is enabled for the font (non-zero means enabled).
+  pub fn TTF_GetFontKerning(font: *const TTF_Font) -> c_int;
+
+  /// Enables (non-zero) or disables (zero) kerning for the font.
+  pub fn TTF_SetFontKerning(font: *mut TTF_Font, allowed: c_int);
+
+  /// Gets if Signed Distance Field rendering is enabled for the font.
+  pub fn TTF_GetFontSDF(font: *const TTF_Font) -> SDL_bool;
+
+  /// Enables or disables Signed Distance Field rendering for the font.
+  ///
+  /// SDF glyphs can be scaled up smoothly when drawn with a shader made for
+  /// them, and only work with the `Blended` render functions.
+  ///
+  /// **Returns:** 0 on success, or -1 if SDF isn't available.
+  pub fn TTF_SetFontSDF(font: *mut TTF_Font, on_off: SDL_bool) -> c_int;
+
+  /// Gets how the lines of wrapped text are aligned.
+  pub fn TTF_GetFontWrappedAlign(font: *const TTF_Font) -> TTF_WrappedAlign;
+
+  /// Sets how the lines of wrapped text are aligned.
+  pub fn TTF_SetFontWrappedAlign(font: *mut TTF_Font, align: TTF_WrappedAlign);
+
+  /// The maximum pixel height of all glyphs of the font.
+  pub fn TTF_FontHeight(font: *const TTF_Font) -> c_int;
+
+  /// The offset from the baseline to the top of the font (positive).
+  pub fn TTF_FontAscent(font: *const TTF_Font) -> c_int;
+
+  /// The offset from the baseline to the bottom of the font (negative).
+  pub fn TTF_FontDescent(font: *const TTF_Font) -> c_int;
+
+  /// The recommended pixel spacing between lines of text.
+  pub fn TTF_FontLineSkip(font: *const TTF_Font) -> c_int;
+
+  /// The number of faces in the font file.
+  pub fn TTF_FontFaces(font: *const TTF_Font) -> c_long;
+
+  /// Non-zero if the current face of the font is fixed width.
+  pub fn TTF_FontFaceIsFixedWidth(font: *const TTF_Font) -> c_int;
+
+  /// The family name of the current face (borrowed), or NULL.
+  pub fn TTF_FontFaceFamilyName(font: *const TTF_Font) -> *const c_char;
+
+  /// The style name of the current face (borrowed), or NULL.
+  pub fn TTF_FontFaceStyleName(font: *const TTF_Font) -> *const c_char;
+
+  /// Checks if the font has a glyph for a codepoint.
+  ///
+  /// **Returns:** the non-zero glyph index, or 0 if there's no glyph.
+  pub fn TTF_GlyphIsProvided32(font: *mut TTF_Font, ch: Uint32) -> c_int;
+
+  /// Gets the metrics of the glyph for a codepoint.
+  ///
+  /// Any of the output pointers can be NULL if you don't need that value.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_GlyphMetrics32(
+    font: *mut TTF_Font, ch: Uint32, minx: *mut c_int, maxx: *mut c_int,
+    miny: *mut c_int, maxy: *mut c_int, advance: *mut c_int,
+  ) -> c_int;
+
+  /// Gets the size that a line of text would be rendered at, without
+  /// rendering it.
+  ///
+  /// Either output pointer can be NULL if you don't need that value.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SizeUTF8(
+    font: *mut TTF_Font, text: *const c_char, w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+
+  /// Gets how much of a line of text fits within a width, without rendering
+  /// it.
+  ///
+  /// * `measure_width` the width available, in pixels.
+  /// * `extent` gets the width of the text that fits (can be NULL).
+  /// * `count` gets the number of characters that fit (can be NULL).
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_MeasureUTF8(
+    font: *mut TTF_Font, text: *const c_char, measure_width: c_int,
+    extent: *mut c_int, count: *mut c_int,
+  ) -> c_int;
+
+  /// Renders text quickly, to an 8-bit palettized surface.
+  ///
+  /// Pixel value 0 is the transparent colorkey, and pixel value 1 is `fg`.
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn TTF_RenderUTF8_Solid(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// As [`TTF_RenderUTF8_Solid`], but lines are wrapped at `wrapLength`
+  /// pixels (or only at newlines if `wrapLength` is 0).
+  pub fn TTF_RenderUTF8_Solid_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders antialiased text to an 8-bit palettized surface, on a solid
+  /// background color.
+  ///
+  /// Pixel value 0 is `bg`, and the other pixel values blend from `bg` to `fg`.
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn TTF_RenderUTF8_Shaded(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// As [`TTF_RenderUTF8_Shaded`], but lines are wrapped at `wrapLength`
+  /// pixels (or only at newlines if `wrapLength` is 0).
+  pub fn TTF_RenderUTF8_Shaded_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders high quality antialiased text to a 32-bit ARGB surface, using
+  /// alpha blending to dither the edges.
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn TTF_RenderUTF8_Blended(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// As [`TTF_RenderUTF8_Blended`], but lines are wrapped at `wrapLength`
+  /// pixels (or only at newlines if `wrapLength` is 0).
+  pub fn TTF_RenderUTF8_Blended_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders subpixel (LCD) antialiased text to a 32-bit ARGB surface, on a
+  /// solid background color.
+  ///
+  /// **Returns:** the new surface, or NULL if there was an error.
+  pub fn TTF_RenderUTF8_LCD(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// As [`TTF_RenderUTF8_LCD`], but lines are wrapped at `wrapLength` pixels
+  /// (or only at newlines if `wrapLength` is 0).
+  pub fn TTF_RenderUTF8_LCD_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph quickly, as with [`TTF_RenderUTF8_Solid`].
+  pub fn TTF_RenderGlyph32_Solid(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph on a background, as with
+  /// [`TTF_RenderUTF8_Shaded`].
+  pub fn TTF_RenderGlyph32_Shaded(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph with alpha blending, as with
+  /// [`TTF_RenderUTF8_Blended`].
+  pub fn TTF_RenderGlyph32_Blended(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+}
@@ -44,7 +44,7 @@ pub fn SDL_VERSION(x: &mut SDL_version) {
   x.patch = SDL_PATCHLEVEL;
 }
 
-extern "C" {
+sdl_extern! {
   /// Get the version of SDL that is being used at runtime.
   ///
   /// It is possible that the runtime SDL version will be higher than the
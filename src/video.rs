@@ -30,7 +30,7 @@ pub struct SDL_DisplayMode {
 pub struct SDL_Window(c_void);
 
 /// The flags on a window
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_WindowFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_WindowFlags);
@@ -84,6 +84,30 @@ pub const SDL_WINDOW_POPUP_MENU: SDL_WindowFlags = SDL_WindowFlags(0x00080000);
 pub const SDL_WINDOW_VULKAN: SDL_WindowFlags = SDL_WindowFlags(0x10000000);
 #[allow(missing_docs)]
 pub const SDL_WINDOW_METAL: SDL_WindowFlags = SDL_WindowFlags(0x20000000);
+impl_names_for_tuple_newtype!(flags SDL_WindowFlags {
+  SDL_WINDOW_FULLSCREEN,
+  SDL_WINDOW_OPENGL,
+  SDL_WINDOW_SHOWN,
+  SDL_WINDOW_HIDDEN,
+  SDL_WINDOW_BORDERLESS,
+  SDL_WINDOW_RESIZABLE,
+  SDL_WINDOW_MINIMIZED,
+  SDL_WINDOW_MAXIMIZED,
+  SDL_WINDOW_INPUT_GRABBED,
+  SDL_WINDOW_INPUT_FOCUS,
+  SDL_WINDOW_MOUSE_FOCUS,
+  SDL_WINDOW_FULLSCREEN_DESKTOP,
+  SDL_WINDOW_FOREIGN,
+  SDL_WINDOW_ALLOW_HIGHDPI,
+  SDL_WINDOW_MOUSE_CAPTURE,
+  SDL_WINDOW_ALWAYS_ON_TOP,
+  SDL_WINDOW_SKIP_TASKBAR,
+  SDL_WINDOW_UTILITY,
+  SDL_WINDOW_TOOLTIP,
+  SDL_WINDOW_POPUP_MENU,
+  SDL_WINDOW_VULKAN,
+  SDL_WINDOW_METAL,
+});
 
 /// Specifies that you don't care about the window position.
 pub const SDL_WINDOWPOS_UNDEFINED: i32 = 0x1FFF0000;
@@ -95,7 +119,7 @@ pub const SDL_WINDOWPOS_CENTERED: i32 = 0x2FFF0000;
 ///
 /// Technically a `u32`, altered to be `u8` to better fit with the usage within
 /// the API.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_WindowEventID(pub u8);
 #[allow(missing_docs)]
@@ -134,12 +158,31 @@ pub const SDL_WINDOWEVENT_CLOSE: SDL_WindowEventID = SDL_WindowEventID(14);
 pub const SDL_WINDOWEVENT_TAKE_FOCUS: SDL_WindowEventID = SDL_WindowEventID(15);
 #[allow(missing_docs)]
 pub const SDL_WINDOWEVENT_HIT_TEST: SDL_WindowEventID = SDL_WindowEventID(16);
+impl_names_for_tuple_newtype!(SDL_WindowEventID {
+  SDL_WINDOWEVENT_NONE,
+  SDL_WINDOWEVENT_SHOWN,
+  SDL_WINDOWEVENT_HIDDEN,
+  SDL_WINDOWEVENT_EXPOSED,
+  SDL_WINDOWEVENT_MOVED,
+  SDL_WINDOWEVENT_RESIZED,
+  SDL_WINDOWEVENT_SIZE_CHANGED,
+  SDL_WINDOWEVENT_MINIMIZED,
+  SDL_WINDOWEVENT_MAXIMIZED,
+  SDL_WINDOWEVENT_RESTORED,
+  SDL_WINDOWEVENT_ENTER,
+  SDL_WINDOWEVENT_LEAVE,
+  SDL_WINDOWEVENT_FOCUS_GAINED,
+  SDL_WINDOWEVENT_FOCUS_LOST,
+  SDL_WINDOWEVENT_CLOSE,
+  SDL_WINDOWEVENT_TAKE_FOCUS,
+  SDL_WINDOWEVENT_HIT_TEST,
+});
 
 /// Event subtype for display events.
 ///
 /// Technically a `u32`, altered to be `u8` to better fit with the usage within
 /// the API.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_DisplayEventID(pub u8);
 /// Never used
@@ -153,9 +196,15 @@ pub const SDL_DISPLAYEVENT_CONNECTED: SDL_DisplayEventID =
 /// Display has been removed from the system
 pub const SDL_DISPLAYEVENT_DISCONNECTED: SDL_DisplayEventID =
   SDL_DisplayEventID(3);
+impl_names_for_tuple_newtype!(SDL_DisplayEventID {
+  SDL_DISPLAYEVENT_NONE,
+  SDL_DISPLAYEVENT_ORIENTATION,
+  SDL_DISPLAYEVENT_CONNECTED,
+  SDL_DISPLAYEVENT_DISCONNECTED,
+});
 
 /// Orientations a display can have.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_DisplayOrientation(pub u32);
 #[allow(missing_docs)]
@@ -173,6 +222,13 @@ pub const SDL_ORIENTATION_PORTRAIT: SDL_DisplayOrientation =
 #[allow(missing_docs)]
 pub const SDL_ORIENTATION_PORTRAIT_FLIPPED: SDL_DisplayOrientation =
   SDL_DisplayOrientation(4);
+impl_names_for_tuple_newtype!(SDL_DisplayOrientation {
+  SDL_ORIENTATION_UNKNOWN,
+  SDL_ORIENTATION_LANDSCAPE,
+  SDL_ORIENTATION_LANDSCAPE_FLIPPED,
+  SDL_ORIENTATION_PORTRAIT,
+  SDL_ORIENTATION_PORTRAIT_FLIPPED,
+});
 
 /// A handle to an OpenGL context.
 #[derive(Debug, Clone, Copy)]
@@ -187,7 +243,7 @@ impl SDL_GLContext {
 }
 
 /// OpenGL configuration attributes
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GLattr(pub u32);
 #[allow(missing_docs)]
@@ -244,11 +300,40 @@ pub const SDL_GL_CONTEXT_RELEASE_BEHAVIOR: SDL_GLattr = SDL_GLattr(24);
 pub const SDL_GL_CONTEXT_RESET_NOTIFICATION: SDL_GLattr = SDL_GLattr(25);
 #[allow(missing_docs)]
 pub const SDL_GL_CONTEXT_NO_ERROR: SDL_GLattr = SDL_GLattr(26);
+impl_names_for_tuple_newtype!(SDL_GLattr {
+  SDL_GL_RED_SIZE,
+  SDL_GL_GREEN_SIZE,
+  SDL_GL_BLUE_SIZE,
+  SDL_GL_ALPHA_SIZE,
+  SDL_GL_BUFFER_SIZE,
+  SDL_GL_DOUBLEBUFFER,
+  SDL_GL_DEPTH_SIZE,
+  SDL_GL_STENCIL_SIZE,
+  SDL_GL_ACCUM_RED_SIZE,
+  SDL_GL_ACCUM_GREEN_SIZE,
+  SDL_GL_ACCUM_BLUE_SIZE,
+  SDL_GL_ACCUM_ALPHA_SIZE,
+  SDL_GL_STEREO,
+  SDL_GL_MULTISAMPLEBUFFERS,
+  SDL_GL_MULTISAMPLESAMPLES,
+  SDL_GL_ACCELERATED_VISUAL,
+  SDL_GL_RETAINED_BACKING,
+  SDL_GL_CONTEXT_MAJOR_VERSION,
+  SDL_GL_CONTEXT_MINOR_VERSION,
+  SDL_GL_CONTEXT_EGL,
+  SDL_GL_CONTEXT_FLAGS,
+  SDL_GL_CONTEXT_PROFILE_MASK,
+  SDL_GL_SHARE_WITH_CURRENT_CONTEXT,
+  SDL_GL_FRAMEBUFFER_SRGB_CAPABLE,
+  SDL_GL_CONTEXT_RELEASE_BEHAVIOR,
+  SDL_GL_CONTEXT_RESET_NOTIFICATION,
+  SDL_GL_CONTEXT_NO_ERROR,
+});
 
 /// The GL Profile: Core, Compatibility, or ES.
 ///
 /// See the `SDL_GL_CONTEXT_PROFILE_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GLprofile(pub u32);
 #[allow(missing_docs)]
@@ -258,11 +343,16 @@ pub const SDL_GL_CONTEXT_PROFILE_COMPATIBILITY: SDL_GLprofile =
   SDL_GLprofile(0x0002);
 #[allow(missing_docs)]
 pub const SDL_GL_CONTEXT_PROFILE_ES: SDL_GLprofile = SDL_GLprofile(0x0004);
+impl_names_for_tuple_newtype!(SDL_GLprofile {
+  SDL_GL_CONTEXT_PROFILE_CORE,
+  SDL_GL_CONTEXT_PROFILE_COMPATIBILITY,
+  SDL_GL_CONTEXT_PROFILE_ES,
+});
 
 /// SDL Context Flags.
 ///
 /// See the `SDL_GL_CONTEXT_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GLcontextFlag(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_GLcontextFlag);
@@ -278,11 +368,17 @@ pub const SDL_GL_CONTEXT_ROBUST_ACCESS_FLAG: SDL_GLcontextFlag =
 #[allow(missing_docs)]
 pub const SDL_GL_CONTEXT_RESET_ISOLATION_FLAG: SDL_GLcontextFlag =
   SDL_GLcontextFlag(0x0008);
+impl_names_for_tuple_newtype!(flags SDL_GLcontextFlag {
+  SDL_GL_CONTEXT_DEBUG_FLAG,
+  SDL_GL_CONTEXT_FORWARD_COMPATIBLE_FLAG,
+  SDL_GL_CONTEXT_ROBUST_ACCESS_FLAG,
+  SDL_GL_CONTEXT_RESET_ISOLATION_FLAG,
+});
 
 /// Affects GL's behavior when you release the context.
 ///
 /// See `SDL_GL_CONTEXT_RELEASE_BEHAVIOR_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GLcontextReleaseFlag(pub u32);
 #[allow(missing_docs)]
@@ -291,11 +387,15 @@ pub const SDL_GL_CONTEXT_RELEASE_BEHAVIOR_NONE: SDL_GLcontextReleaseFlag =
 #[allow(missing_docs)]
 pub const SDL_GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH: SDL_GLcontextReleaseFlag =
   SDL_GLcontextReleaseFlag(0x0001);
+impl_names_for_tuple_newtype!(SDL_GLcontextReleaseFlag {
+  SDL_GL_CONTEXT_RELEASE_BEHAVIOR_NONE,
+  SDL_GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH,
+});
 
 /// Affects GL's behavior when the context is reset.
 ///
 /// See `SDL_GL_CONTEXT_RESET_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_GLContextResetNotification(pub u32);
 #[allow(missing_docs)]
@@ -304,11 +404,15 @@ pub const SDL_GL_CONTEXT_RESET_NO_NOTIFICATION: SDL_GLContextResetNotification =
 #[allow(missing_docs)]
 pub const SDL_GL_CONTEXT_RESET_LOSE_CONTEXT: SDL_GLContextResetNotification =
   SDL_GLContextResetNotification(0x0001);
+impl_names_for_tuple_newtype!(SDL_GLContextResetNotification {
+  SDL_GL_CONTEXT_RESET_NO_NOTIFICATION,
+  SDL_GL_CONTEXT_RESET_LOSE_CONTEXT,
+});
 
 /// The results of a hit test.
 ///
 /// See `SDL_HITTEST_*`
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_HitTestResult(pub u32);
 #[allow(missing_docs)]
@@ -333,6 +437,18 @@ pub const SDL_HITTEST_RESIZE_BOTTOMLEFT: SDL_HitTestResult =
   SDL_HitTestResult(8);
 #[allow(missing_docs)]
 pub const SDL_HITTEST_RESIZE_LEFT: SDL_HitTestResult = SDL_HitTestResult(9);
+impl_names_for_tuple_newtype!(SDL_HitTestResult {
+  SDL_HITTEST_NORMAL,
+  SDL_HITTEST_DRAGGABLE,
+  SDL_HITTEST_RESIZE_TOPLEFT,
+  SDL_HITTEST_RESIZE_TOP,
+  SDL_HITTEST_RESIZE_TOPRIGHT,
+  SDL_HITTEST_RESIZE_RIGHT,
+  SDL_HITTEST_RESIZE_BOTTOMRIGHT,
+  SDL_HITTEST_RESIZE_BOTTOM,
+  SDL_HITTEST_RESIZE_BOTTOMLEFT,
+  SDL_HITTEST_RESIZE_LEFT,
+});
 
 /// SDL's "hit test" function pointer type.
 pub type SDL_HitTest = Option<
@@ -346,7 +462,7 @@ pub type SDL_HitTest = Option<
 /// Window flash operation
 ///
 /// See [SDL_FlashWindow]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SDL_FlashOperation(pub u32);
 /// Cancel any window flash state
@@ -355,8 +471,13 @@ pub const SDL_FLASH_CANCEL: SDL_FlashOperation = SDL_FlashOperation(0);
 pub const SDL_FLASH_BRIEFLY: SDL_FlashOperation = SDL_FlashOperation(1);
 /// Flash the window until it gets focus
 pub const SDL_FLASH_UNTIL_FOCUSED: SDL_FlashOperation = SDL_FlashOperation(2);
+impl_names_for_tuple_newtype!(SDL_FlashOperation {
+  SDL_FLASH_CANCEL,
+  SDL_FLASH_BRIEFLY,
+  SDL_FLASH_UNTIL_FOCUSED,
+});
 
-extern "C" {
+sdl_extern! {
   /// The number of available video drivers.
   ///
   /// **Returns:** >=1 on success, or negative on error (call `SDL_GetError`)
@@ -883,6 +1004,8 @@ extern "C" {
   /// * `operation` the flash operation
   /// * **Returns:** 0 on success, or a negative error code on failure. Call
   ///   [SDL_GetError] for more information.
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_FlashWindow(
     window: *mut SDL_Window, operation: SDL_FlashOperation,
   ) -> c_int;
@@ -898,6 +1021,8 @@ extern "C" {
   ///   disable.
   ///
   /// See Also: [SDL_SetWindowAlwaysOnTop]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_SetWindowAlwaysOnTop(window: *mut SDL_Window, on_top: SDL_bool);
 
   /// Set a window's keyboard grab mode.
@@ -910,6 +1035,8 @@ extern "C" {
   ///
   /// See Also: [SDL_GetWindowKeyboardGrab], [SDL_SetWindowMouseGrab],
   /// [SDL_SetWindowGrab]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_SetWindowKeyboardGrab(window: *mut SDL_Window, grabbed: SDL_bool);
 
   /// Set a window's mouse grab mode.
@@ -918,6 +1045,8 @@ extern "C" {
   ///
   /// See Also: [SDL_GetWindowMouseGrab], [SDL_SetWindowKeyboardGrab],
   /// [SDL_SetWindowGrab]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_SetWindowMouseGrab(window: *mut SDL_Window, grabbed: SDL_bool);
 
   /// Get a window's keyboard grab mode.
@@ -927,6 +1056,8 @@ extern "C" {
   ///   otherwise.
   ///
   /// See Also: [SDL_SetWindowKeyboardGrab], [SDL_GetWindowGrab]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_GetWindowKeyboardGrab(window: *mut SDL_Window) -> SDL_bool;
 
   /// Get a window's mouse grab mode.
@@ -935,5 +1066,7 @@ extern "C" {
   /// * **Returns:** `SDL_TRUE` if mouse is grabbed, and `SDL_FALSE` otherwise.
   ///
   /// See Also: [SDL_SetWindowKeyboardGrab], [SDL_GetWindowGrab]
+  #[cfg(feature = "sdl_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl_2_0_16")))]
   pub fn SDL_GetWindowMouseGrab(window: *mut SDL_Window) -> SDL_bool;
 }
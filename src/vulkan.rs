@@ -16,7 +16,7 @@ pub type SDL_vulkanInstance = raw_vulkan_handle::VkInstance;
 /// Alternate type name in some docs.
 pub type SDL_vulkanSurface = raw_vulkan_handle::VkSurfaceKHR;
 
-extern "C" {
+sdl_extern! {
   /// Dynamically load a Vulkan loader library.
   ///
   /// * \[in\] `path` The platform dependent Vulkan loader library name, or
@@ -0,0 +1,35 @@
+use fermium::prelude::*;
+
+#[test]
+fn constants_have_names() {
+  assert_eq!(SDL_KEYDOWN.name(), Some("SDL_KEYDOWN"));
+  assert_eq!(SDL_EventType(0x7777).name(), None);
+  assert_eq!(SDL_SCANCODE_A.name(), Some("SDL_SCANCODE_A"));
+  assert_eq!(SDL_WINDOW_SHOWN.name(), Some("SDL_WINDOW_SHOWN"));
+  assert_eq!((SDL_WINDOW_SHOWN | SDL_WINDOW_OPENGL).name(), None);
+}
+
+#[test]
+fn debug_prints_names() {
+  assert_eq!(format!("{:?}", SDL_KEYDOWN), "SDL_KEYDOWN");
+  assert_eq!(format!("{:?}", SDL_EventType(0x7777)), "SDL_EventType(30583)");
+  assert_eq!(
+    format!("{:?}", SDL_WINDOW_OPENGL | SDL_WINDOW_SHOWN),
+    "SDL_WINDOW_OPENGL | SDL_WINDOW_SHOWN"
+  );
+  assert_eq!(
+    format!("{:?}", SDL_WINDOW_OPENGL | SDL_WindowFlags(0x8000_0000)),
+    "SDL_WINDOW_OPENGL | 0x80000000"
+  );
+  assert_eq!(format!("{:?}", SDL_WindowFlags(0)), "SDL_WindowFlags(0)");
+}
+
+#[test]
+fn debug_for_sdl_event() {
+  let mut event = SDL_Event::default();
+  event.quit = SDL_QuitEvent { type_: SDL_QUIT, timestamp: 5 };
+  assert_eq!(
+    format!("{event:?}"),
+    "Quit(SDL_QuitEvent { type_: SDL_QUIT, timestamp: 5 })"
+  );
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "dynamic_loading")]
+
+//! These don't need SDL2 to be installed, since nothing links to it.
+
+use core::ffi::CStr;
+use fermium::{dynamic_loading::is_loaded, load, LoadError};
+
+#[test]
+fn load_reports_missing_library_or_function() {
+  let result = unsafe {
+    load(Some(CStr::from_bytes_with_nul(b"this-is-not-sdl2.so\0").unwrap()))
+  };
+  assert_eq!(result, Err(LoadError::LibraryNotFound));
+  assert!(!is_loaded());
+
+  // A library that opens fine, but isn't SDL2.
+  #[cfg(all(target_os = "linux", target_env = "gnu"))]
+  {
+    let result =
+      unsafe { load(Some(CStr::from_bytes_with_nul(b"libc.so.6\0").unwrap())) };
+    assert_eq!(result, Err(LoadError::MissingFunction("SDL_Init")));
+    assert!(!is_loaded());
+  }
+}
+
+#[test]
+#[should_panic(expected = "before `fermium::load` succeeded")]
+fn calling_before_load_panics() {
+  unsafe { fermium::timer::SDL_GetTicks() };
+}
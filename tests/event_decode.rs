@@ -0,0 +1,47 @@
+use fermium::prelude::*;
+
+#[test]
+fn decode_picks_the_union_field() {
+  let mut event = SDL_Event::default();
+  event.key = SDL_KeyboardEvent {
+    type_: SDL_KEYUP,
+    timestamp: 7,
+    repeat: 1,
+    ..Default::default()
+  };
+  match event.decode() {
+    Event::Keyboard(key) => {
+      assert_eq!(key.type_, SDL_KEYUP);
+      assert_eq!(key.timestamp, 7);
+      assert_eq!(key.repeat, 1);
+    }
+    other => panic!("{other:?}"),
+  }
+
+  event.type_ = SDL_QUIT;
+  assert!(matches!(Event::from(event), Event::Quit(_)));
+  event.type_ = SDL_CLIPBOARDUPDATE;
+  assert!(matches!(event.decode(), Event::Common(_)));
+}
+
+#[test]
+fn decode_user_and_unknown_types() {
+  let mut event = SDL_Event::default();
+  event.user = SDL_UserEvent {
+    type_: SDL_EventType(SDL_USEREVENT.0 + 3),
+    code: 12,
+    ..Default::default()
+  };
+  match event.decode() {
+    Event::User(user) => assert_eq!(user.code, 12),
+    other => panic!("{other:?}"),
+  }
+
+  event.type_ = SDL_LASTEVENT;
+  assert!(matches!(event.decode(), Event::Unknown(_)));
+  event.type_ = SDL_EventType(0x1234);
+  match event.decode() {
+    Event::Unknown(common) => assert_eq!(common.type_, SDL_EventType(0x1234)),
+    other => panic!("{other:?}"),
+  }
+}
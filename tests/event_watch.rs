@@ -0,0 +1,71 @@
+#![cfg(all(feature = "alloc", not(feature = "dynamic_loading")))]
+
+use fermium::prelude::*;
+use std::sync::{
+  atomic::{AtomicU32, Ordering},
+  Arc,
+};
+
+unsafe fn push_user(code: i32) {
+  let mut event = SDL_Event::default();
+  event.user =
+    SDL_UserEvent { type_: SDL_USEREVENT, code, ..Default::default() };
+  SDL_PushEvent(&mut event);
+}
+
+unsafe fn queued_user_codes() -> Vec<i32> {
+  poll_iter()
+    .filter_map(|event| match event {
+      Event::User(user) => Some(user.code),
+      _ => None,
+    })
+    .collect()
+}
+
+#[test]
+fn watchers_and_filters_take_closures() {
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_EVENTS), 0);
+    SDL_FlushEvents(SDL_FIRSTEVENT, SDL_LASTEVENT);
+
+    let seen = Arc::new(AtomicU32::new(0));
+    let seen_in_watch = seen.clone();
+    let watch = add_event_watch(move |event| {
+      if event.type_ == SDL_USEREVENT {
+        seen_in_watch.fetch_add(1, Ordering::SeqCst);
+      }
+    });
+    let filter = set_event_filter(|event| event.user.code != 1);
+
+    push_user(1);
+    push_user(2);
+    assert_eq!(queued_user_codes(), [2]);
+    assert_eq!(seen.load(Ordering::SeqCst), 1);
+
+    drop(filter);
+    drop(watch);
+    push_user(1);
+    push_user(3);
+    assert_eq!(seen.load(Ordering::SeqCst), 1);
+
+    filter_events(|event| event.user.code != 3);
+    assert_eq!(queued_user_codes(), [1]);
+
+    // a watcher that pushes an event isn't called again for that event.
+    let seen_in_watch = seen.clone();
+    let watch = add_event_watch(move |event| {
+      if event.type_ == SDL_USEREVENT {
+        seen_in_watch.fetch_add(1, Ordering::SeqCst);
+        push_user(5);
+      }
+    });
+    push_user(4);
+    drop(watch);
+    assert_eq!(seen.load(Ordering::SeqCst), 2);
+    let mut codes = queued_user_codes();
+    codes.sort_unstable();
+    assert_eq!(codes, [4, 5]);
+
+    SDL_Quit();
+  }
+}
@@ -0,0 +1,70 @@
+//! Checks that `hints.rs` declares every hint in the bundled `SDL_hints.h`.
+//!
+//! This only reads the source files, so it doesn't need SDL2 to run.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+fn bundled_sdl_dir() -> PathBuf {
+  let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+  fs::read_dir(&manifest_dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .find(|path| {
+      let name = path.file_name().unwrap().to_string_lossy();
+      name.starts_with("SDL2-") && !name.ends_with("-devel") && path.is_dir()
+    })
+    .expect("couldn't find the bundled SDL2 source folder")
+}
+
+/// Collects `NAME -> "value"` for every `SDL_HINT_*` string in the text.
+///
+/// The header has `#define SDL_HINT_FOO "SDL_FOO"` and the module has
+/// `pub const SDL_HINT_FOO: &[u8] = c_str!("SDL_FOO");`, so in both cases we
+/// just look for the name and then the next string literal.
+fn collect_hints(text: &str, decl_prefix: &str) -> BTreeMap<String, String> {
+  let mut out = BTreeMap::new();
+  let mut rest = text;
+  while let Some(i) = rest.find(decl_prefix) {
+    rest = &rest[i + decl_prefix.len()..];
+    let name_len = rest
+      .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+      .unwrap_or(rest.len());
+    let name = &rest[..name_len];
+    let after_name = &rest[name_len..];
+    let open = match after_name.find('"') {
+      Some(open) if !after_name[..open].contains(';') => open,
+      // not a string declaration (eg: `SDL_HINT_DEFAULT`)
+      _ => continue,
+    };
+    let close = after_name[open + 1..].find('"').unwrap();
+    let value = &after_name[open + 1..open + 1 + close];
+    out.insert(format!("SDL_HINT_{name}"), value.to_string());
+    rest = &after_name[open + 1 + close..];
+  }
+  out
+}
+
+#[test]
+fn every_header_hint_is_declared() {
+  let header_path = bundled_sdl_dir().join("include").join("SDL_hints.h");
+  let header = fs::read_to_string(&header_path).unwrap();
+  let module_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src").join("hints.rs");
+  let module = fs::read_to_string(module_path).unwrap();
+
+  let header_hints = collect_hints(&header, "#define SDL_HINT_");
+  let module_hints = collect_hints(&module, "pub const SDL_HINT_");
+  assert!(header_hints.len() > 100, "header parse failed: {header_path:?}");
+
+  let mut problems = Vec::new();
+  for (name, value) in &header_hints {
+    match module_hints.get(name) {
+      None => problems.push(format!("missing: {name}")),
+      Some(v) if v != value => {
+        problems.push(format!("wrong value: {name} is {v:?}, not {value:?}"))
+      }
+      Some(_) => (),
+    }
+  }
+  assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
@@ -1,3 +1,5 @@
+#![cfg(not(feature = "dynamic_loading"))]
+
 use fermium::*;
 
 #[test]
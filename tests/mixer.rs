@@ -0,0 +1,67 @@
+#![cfg(feature = "mixer")]
+
+use fermium::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static EFFECT_CALLS: AtomicU32 = AtomicU32::new(0);
+
+unsafe extern "C" fn count_effect_calls(
+  _chan: c_int, _stream: *mut c_void, _len: c_int, _udata: *mut c_void,
+) {
+  EFFECT_CALLS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn mixer_plays_a_chunk_on_the_dummy_driver() {
+  std::env::set_var("SDL_AUDIODRIVER", "dummy");
+  unsafe {
+    assert_eq!(
+      Mix_OpenAudioDevice(
+        MIX_DEFAULT_FREQUENCY,
+        MIX_DEFAULT_FORMAT,
+        MIX_DEFAULT_CHANNELS,
+        1024,
+        core::ptr::null(),
+        0
+      ),
+      0
+    );
+    let mut frequency = 0;
+    let mut format = SDL_AudioFormat(0);
+    let mut channels = 0;
+    assert_ne!(Mix_QuerySpec(&mut frequency, &mut format, &mut channels), 0);
+    assert_eq!(frequency, MIX_DEFAULT_FREQUENCY);
+    assert_eq!(format, MIX_DEFAULT_FORMAT);
+    assert_eq!(channels, MIX_DEFAULT_CHANNELS);
+
+    // a tenth of a second of silence.
+    let mut samples = vec![0_i16; (frequency / 10 * channels) as usize];
+    let chunk = Mix_QuickLoad_RAW(
+      samples.as_mut_ptr().cast(),
+      (samples.len() * 2) as u32,
+    );
+    assert!(!chunk.is_null());
+    let channel = Mix_PlayChannel(-1, chunk, 0);
+    assert!(channel >= 0);
+    assert_ne!(
+      Mix_RegisterEffect(
+        channel,
+        Some(count_effect_calls),
+        None,
+        core::ptr::null_mut()
+      ),
+      0
+    );
+    let start = SDL_GetTicks();
+    while Mix_Playing(channel) != 0 && SDL_GetTicks() - start < 2000 {
+      SDL_Delay(10);
+    }
+    assert_eq!(Mix_Playing(channel), 0);
+    assert!(EFFECT_CALLS.load(Ordering::SeqCst) > 0);
+
+    Mix_FreeChunk(chunk);
+    Mix_CloseAudio();
+    Mix_Quit();
+    SDL_Quit();
+  }
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "net")]
+
+use fermium::prelude::*;
+
+#[test]
+fn tcp_over_loopback() {
+  unsafe {
+    assert_eq!(SDLNet_Init(), 0);
+    let port = 40_000 + (std::process::id() % 20_000) as u16;
+
+    let mut listen_address = IPaddress::default();
+    assert_eq!(
+      SDLNet_ResolveHost(&mut listen_address, core::ptr::null(), port),
+      0
+    );
+    assert_eq!(listen_address.host, INADDR_ANY);
+    let server = SDLNet_TCP_Open(&mut listen_address);
+    assert!(!server.is_null());
+
+    let mut server_address = IPaddress::default();
+    assert_eq!(
+      SDLNet_ResolveHost(
+        &mut server_address,
+        b"127.0.0.1\0".as_ptr().cast(),
+        port
+      ),
+      0
+    );
+    assert_eq!(server_address.host, INADDR_LOOPBACK.to_be());
+    assert_eq!(server_address.port, port.to_be());
+    let client = SDLNet_TCP_Open(&mut server_address);
+    assert!(!client.is_null());
+
+    // the listening socket becomes ready when a connection is waiting.
+    let set = SDLNet_AllocSocketSet(1);
+    assert!(!set.is_null());
+    assert_eq!(SDLNet_TCP_AddSocket(set, server), 1);
+    assert_eq!(SDLNet_CheckSockets(set, 2000), 1);
+    assert!(SDLNet_SocketReady(server));
+    let accepted = SDLNet_TCP_Accept(server);
+    assert!(!accepted.is_null());
+    assert!(!SDLNet_TCP_GetPeerAddress(accepted).is_null());
+
+    let message = b"hello";
+    assert_eq!(
+      SDLNet_TCP_Send(client, message.as_ptr().cast(), message.len() as c_int),
+      message.len() as c_int
+    );
+    let mut buffer = [0_u8; 16];
+    let received = SDLNet_TCP_Recv(
+      accepted,
+      buffer.as_mut_ptr().cast(),
+      buffer.len() as c_int,
+    );
+    assert_eq!(&buffer[..received as usize], message);
+
+    assert_eq!(SDLNet_TCP_DelSocket(set, server), 0);
+    SDLNet_FreeSocketSet(set);
+    SDLNet_TCP_Close(accepted);
+    SDLNet_TCP_Close(client);
+    SDLNet_TCP_Close(server);
+    SDLNet_Quit();
+  }
+}
+
+#[test]
+fn udp_over_loopback() {
+  unsafe {
+    assert_eq!(SDLNet_Init(), 0);
+    let sender = SDLNet_UDP_Open(0);
+    assert!(!sender.is_null());
+    let receiver = SDLNet_UDP_Open(0);
+    assert!(!receiver.is_null());
+
+    // port 0 picks a free port, which we can look up afterwards.
+    let receiver_address = SDLNet_UDP_GetPeerAddress(receiver, -1);
+    assert!(!receiver_address.is_null());
+    let destination = IPaddress {
+      host: INADDR_LOOPBACK.to_be(),
+      port: (*receiver_address).port,
+    };
+    assert_ne!(destination.port, 0);
+
+    let message = b"is anyone there?";
+    let packet = SDLNet_AllocPacket(64);
+    assert!(!packet.is_null());
+    core::ptr::copy_nonoverlapping(
+      message.as_ptr(),
+      (*packet).data,
+      message.len(),
+    );
+    (*packet).len = message.len() as c_int;
+    (*packet).address = destination;
+    assert_eq!(SDLNet_UDP_Send(sender, -1, packet), 1);
+
+    let set = SDLNet_AllocSocketSet(1);
+    assert_eq!(SDLNet_UDP_AddSocket(set, receiver), 1);
+    assert_eq!(SDLNet_CheckSockets(set, 2000), 1);
+    assert!(SDLNet_SocketReady(receiver));
+
+    let incoming = SDLNet_AllocPacket(64);
+    assert_eq!(SDLNet_UDP_Recv(receiver, incoming), 1);
+    let data =
+      core::slice::from_raw_parts((*incoming).data, (*incoming).len as usize);
+    assert_eq!(data, message);
+    assert_eq!((*incoming).address.host, INADDR_LOOPBACK.to_be());
+
+    SDLNet_FreePacket(incoming);
+    SDLNet_FreePacket(packet);
+    SDLNet_FreeSocketSet(set);
+    SDLNet_UDP_Close(receiver);
+    SDLNet_UDP_Close(sender);
+    SDLNet_Quit();
+  }
+}
@@ -0,0 +1,131 @@
+#![cfg(all(feature = "alloc", not(feature = "dynamic_loading")))]
+
+use fermium::prelude::*;
+
+fn key_event(
+  type_: SDL_EventType, timestamp: u32, sym: SDL_Keycode,
+) -> SDL_Event {
+  let mut event = SDL_Event::default();
+  event.key = SDL_KeyboardEvent {
+    type_,
+    timestamp,
+    keysym: SDL_Keysym { sym, ..Default::default() },
+    ..Default::default()
+  };
+  event
+}
+
+#[test]
+fn recording_round_trips_in_timestamp_order() {
+  let mut recorder = EventRecorder::new();
+  assert!(recorder.record(&key_event(SDL_KEYDOWN, 20, SDLK_a)));
+  assert!(recorder.record(&key_event(SDL_KEYUP, 10, SDLK_b)));
+  let mut quit = SDL_Event::default();
+  quit.quit = SDL_QuitEvent { type_: SDL_QUIT, timestamp: 30 };
+  assert!(recorder.record(&quit));
+
+  let mut drop = SDL_Event::default();
+  drop.drop = SDL_DropEvent { type_: SDL_DROPFILE, ..Default::default() };
+  assert!(!recorder.record(&drop));
+  let mut user = SDL_Event::default();
+  user.type_ = SDL_USEREVENT;
+  assert!(!recorder.record(&user));
+
+  let bytes = recorder.into_bytes();
+  let player = EventPlayer::new(&bytes).unwrap();
+  let events: Vec<Event> =
+    player.events().iter().map(SDL_Event::decode).collect();
+  match events[..] {
+    [Event::Keyboard(up), Event::Keyboard(down), Event::Quit(quit)] => {
+      assert_eq!(
+        (up.type_, up.timestamp, up.keysym.sym),
+        (SDL_KEYUP, 10, SDLK_b)
+      );
+      assert_eq!(
+        (down.type_, down.timestamp, down.keysym.sym),
+        (SDL_KEYDOWN, 20, SDLK_a)
+      );
+      assert_eq!(quit.timestamp, 30);
+    }
+    _ => panic!("{events:?}"),
+  }
+  assert!(!player.is_finished());
+}
+
+#[test]
+fn every_field_round_trips() {
+  let mut key = key_event(SDL_KEYDOWN, 1, SDLK_a);
+  key.key.windowID = 2;
+  key.key.repeat = 1;
+  key.key.keysym.scancode = SDL_SCANCODE_A;
+  key.key.keysym.mod_ = KMOD_LSHIFT.0 as u16;
+  let mut gesture = SDL_Event::default();
+  gesture.mgesture = SDL_MultiGestureEvent {
+    type_: SDL_MULTIGESTURE,
+    timestamp: 2,
+    touchId: SDL_TouchID(-3),
+    dTheta: 0.5,
+    dDist: -0.25,
+    x: 0.75,
+    y: 1.0,
+    numFingers: 3,
+    padding: 0,
+  };
+
+  let mut recorder = EventRecorder::new();
+  assert!(recorder.record(&key));
+  assert!(recorder.record(&gesture));
+  let player = EventPlayer::new(recorder.as_bytes()).unwrap();
+  match player.events() {
+    [k, g] => unsafe {
+      assert_eq!(k.key, key.key);
+      assert_eq!(g.mgesture, gesture.mgesture);
+    },
+    events => panic!("{} events", events.len()),
+  }
+}
+
+#[test]
+fn bad_recordings_are_refused() {
+  let mut recorder = EventRecorder::new();
+  recorder.record(&key_event(SDL_KEYDOWN, 1, SDLK_a));
+  let bytes = recorder.as_bytes();
+
+  assert!(EventPlayer::new(&bytes[..5]).is_none());
+  assert!(EventPlayer::new(&bytes[..bytes.len() - 1]).is_none());
+  let mut wrong_version = bytes.to_vec();
+  wrong_version[8] += 1;
+  assert!(EventPlayer::new(&wrong_version).is_none());
+
+  // a drop event has a pointer in it, so it can't be loaded.
+  let mut forged = bytes[..10].to_vec();
+  forged.push(4);
+  forged.extend_from_slice(&SDL_DROPFILE.0.to_ne_bytes());
+  assert!(EventPlayer::new(&forged).is_none());
+}
+
+#[test]
+fn recorded_events_replay_through_the_queue() {
+  let mut recorder = EventRecorder::new();
+  recorder.record(&key_event(SDL_KEYDOWN, 5, SDLK_a));
+  recorder.record(&key_event(SDL_KEYUP, 6, SDLK_a));
+  let mut player = EventPlayer::new(recorder.as_bytes()).unwrap();
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_EVENTS), 0);
+    SDL_FlushEvents(SDL_FIRSTEVENT, SDL_LASTEVENT);
+
+    assert_eq!(player.push_all(), Ok(2));
+    assert!(player.is_finished());
+    let mut rerecorder = EventRecorder::new();
+    let keys: Vec<SDL_EventType> = rerecorder
+      .poll_iter()
+      .filter_map(|event| match event {
+        Event::Keyboard(key) => Some(key.type_),
+        _ => None,
+      })
+      .collect();
+    assert_eq!(keys, [SDL_KEYDOWN, SDL_KEYUP]);
+
+    SDL_Quit();
+  }
+}
@@ -0,0 +1,75 @@
+#![cfg(not(feature = "dynamic_loading"))]
+
+use fermium::{prelude::*, synth};
+
+#[test]
+fn synthesized_events_come_out_of_the_queue() {
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_EVENTS), 0);
+    SDL_FlushEvents(SDL_FIRSTEVENT, SDL_LASTEVENT);
+
+    assert_eq!(synth::key_tap(SDL_SCANCODE_A), 1);
+    assert_eq!(
+      synth::type_text("héllo, this text is longer than one event"),
+      1
+    );
+    assert_eq!(synth::mouse_click(SDL_BUTTON_LEFT, 10, 20), 1);
+    assert_eq!(synth::mouse_wheel(0, -1), 1);
+    let which = SDL_JoystickID(3);
+    assert_eq!(synth::controller_button_tap(which, SDL_CONTROLLER_BUTTON_A), 1);
+
+    let mut text = Vec::new();
+    let mut events = Vec::new();
+    let mut event = SDL_Event::default();
+    while SDL_PollEvent(&mut event) == 1 {
+      match event.decode() {
+        Event::TextInput(input) => {
+          let len = input.text.iter().position(|&c| c == 0).unwrap();
+          assert!(len < SDL_TEXTINPUTEVENT_TEXT_SIZE);
+          text.extend(input.text[..len].iter().map(|&c| c as u8));
+        }
+        other => events.push(other),
+      }
+    }
+    assert_eq!(
+      String::from_utf8(text).unwrap(),
+      "héllo, this text is longer than one event"
+    );
+
+    match events[..] {
+      [Event::Keyboard(down), Event::Keyboard(up), Event::MouseButton(press), Event::MouseButton(release), Event::MouseWheel(wheel), Event::ControllerButton(cdown), Event::ControllerButton(cup)] =>
+      {
+        assert_eq!((down.type_, down.state), (SDL_KEYDOWN, SDL_PRESSED));
+        assert_eq!((up.type_, up.state), (SDL_KEYUP, SDL_RELEASED));
+        assert_eq!(down.keysym.scancode, SDL_SCANCODE_A);
+        assert_eq!(down.keysym.sym, SDL_GetKeyFromScancode(SDL_SCANCODE_A));
+        assert_eq!(
+          (press.type_, press.button, press.x, press.y),
+          (SDL_MOUSEBUTTONDOWN, SDL_BUTTON_LEFT as u8, 10, 20)
+        );
+        assert_eq!(release.type_, SDL_MOUSEBUTTONUP);
+        assert_eq!((wheel.x, wheel.y), (0, -1));
+        assert_eq!((cdown.which, cdown.state), (which, SDL_PRESSED));
+        assert_eq!(cup.button, SDL_CONTROLLER_BUTTON_A.0 as u8);
+      }
+      _ => panic!("{events:?}"),
+    }
+
+    // the `é` would be at bytes 30 and 31, so it goes in the second event.
+    let boundary = format!("{}éxyz", "a".repeat(30));
+    assert_eq!(synth::type_text(&boundary), 1);
+    let mut chunks = Vec::new();
+    while SDL_PollEvent(&mut event) == 1 {
+      if let Event::TextInput(input) = event.decode() {
+        let len = input.text.iter().position(|&c| c == 0).unwrap();
+        assert!(len < SDL_TEXTINPUTEVENT_TEXT_SIZE);
+        let bytes: Vec<u8> =
+          input.text[..len].iter().map(|&c| c as u8).collect();
+        chunks.push(String::from_utf8(bytes).unwrap());
+      }
+    }
+    assert_eq!(chunks, ["a".repeat(30), "éxyz".to_string()]);
+
+    SDL_Quit();
+  }
+}
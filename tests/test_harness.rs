@@ -0,0 +1,39 @@
+#![cfg(feature = "test_harness")]
+
+use fermium::test_harness::*;
+
+#[test]
+fn random_context_from_time() {
+  let mut rnd = SDLTest_RandomContext::default();
+  unsafe { SDLTest_RandomInitTime(&mut rnd) };
+  assert_ne!(rnd, SDLTest_RandomContext::default());
+  let first = unsafe { SDLTest_Random(&mut rnd) };
+  let rest: Vec<_> =
+    (0..8).map(|_| unsafe { SDLTest_Random(&mut rnd) }).collect();
+  assert!(rest.iter().any(|&r| r != first));
+}
+
+#[test]
+fn random_context_repeats_for_the_same_seed() {
+  let mut a = SDLTest_RandomContext::default();
+  let mut b = SDLTest_RandomContext::default();
+  unsafe {
+    SDLTest_RandomInit(&mut a, 1234, 5678);
+    SDLTest_RandomInit(&mut b, 1234, 5678);
+    for _ in 0..16 {
+      assert_eq!(SDLTest_Random(&mut a), SDLTest_Random(&mut b));
+    }
+  }
+}
+
+#[test]
+fn fuzzer_integer_in_range() {
+  unsafe {
+    SDLTest_FuzzerInit(42);
+    for _ in 0..100 {
+      let r = SDLTest_RandomIntegerInRange(-5, 5);
+      assert!((-5..=5).contains(&r));
+    }
+    assert!(SDLTest_GetFuzzerInvocationCount() >= 100);
+  }
+}
@@ -0,0 +1,43 @@
+#![cfg(all(feature = "alloc", not(feature = "dynamic_loading")))]
+
+use fermium::prelude::*;
+use std::sync::{
+  atomic::{AtomicU32, Ordering},
+  Arc,
+};
+
+struct CountsDrops(Arc<AtomicU32>);
+impl Drop for CountsDrops {
+  fn drop(&mut self) {
+    self.0.fetch_add(1, Ordering::SeqCst);
+  }
+}
+
+#[test]
+fn user_event_data_round_trips_and_flushes() {
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_EVENTS), 0);
+    let ty = UserEventType::<(u32, String)>::register().unwrap();
+    std::thread::spawn(move || {
+      ty.push((7, String::from("from a thread"))).unwrap();
+    })
+    .join()
+    .unwrap();
+
+    let mut event = SDL_Event::default();
+    assert_eq!(SDL_PollEvent(&mut event), 1);
+    assert!(matches!(event.decode(), Event::User(_)));
+    assert_eq!(ty.take(&mut event), Some((7, String::from("from a thread"))));
+    assert_eq!(ty.take(&mut event), None);
+
+    let drops = Arc::new(AtomicU32::new(0));
+    let dropper = UserEventType::<CountsDrops>::register().unwrap();
+    assert!(dropper.push(CountsDrops(drops.clone())).is_ok());
+    assert!(dropper.push(CountsDrops(drops.clone())).is_ok());
+    assert_eq!(dropper.flush(), 2);
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+    assert_eq!(SDL_HasEvent(dropper.event_type()), SDL_FALSE);
+
+    SDL_Quit();
+  }
+}
@@ -0,0 +1,144 @@
+//! Checks that every declared function newer than SDL 2.0.14 is behind the
+//! matching `sdl_*` version feature, according to the `\since` notes in the
+//! bundled headers, and that each of those has a lookup in `optional`.
+//!
+//! This only reads the source files, so it doesn't need SDL2 to run.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+fn bundled_sdl_dir() -> PathBuf {
+  let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+  fs::read_dir(&manifest_dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .find(|path| {
+      let name = path.file_name().unwrap().to_string_lossy();
+      name.starts_with("SDL2-") && !name.ends_with("-devel") && path.is_dir()
+    })
+    .expect("couldn't find the bundled SDL2 source folder")
+}
+
+/// `SDL_Foo -> (2, 0, 16)` for every function with a `\since` note.
+fn header_since_versions() -> BTreeMap<String, (u32, u32, u32)> {
+  const SINCE: &str = "available since SDL ";
+  let mut out = BTreeMap::new();
+  let include_dir = bundled_sdl_dir().join("include");
+  for entry in fs::read_dir(include_dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().map_or(true, |e| e != "h") {
+      continue;
+    }
+    let text = fs::read_to_string(&path).unwrap_or_default();
+    let mut since: Option<(u32, u32, u32)> = None;
+    for line in text.lines() {
+      if let Some(i) = line.find(SINCE) {
+        let version: String = line[i + SINCE.len()..]
+          .chars()
+          .take_while(|c| c.is_ascii_digit() || *c == '.')
+          .collect();
+        let v: Vec<u32> = version
+          .trim_end_matches('.')
+          .split('.')
+          .map(|n| n.parse().unwrap())
+          .collect();
+        since = Some((v[0], v[1], v[2]));
+      } else if line.starts_with("extern DECLSPEC") {
+        if let (Some(v), Some(paren)) = (since.take(), line.find('(')) {
+          let name =
+            line[..paren].rsplit(|c: char| c.is_whitespace() || c == '*');
+          out.insert(name.into_iter().next().unwrap().to_string(), v);
+        }
+      }
+    }
+  }
+  out
+}
+
+fn feature_for(v: (u32, u32, u32)) -> Option<&'static str> {
+  Some(match v {
+    (2, 0, p) if p <= 14 => return None,
+    (2, 0, 16) => "sdl_2_0_16",
+    (2, 0, 18) => "sdl_2_0_18",
+    (2, 0, 20) => "sdl_2_0_20",
+    (2, 0, 22) => "sdl_2_0_22",
+    (2, 24, _) => "sdl_2_24",
+    (2, 26, _) => "sdl_2_26",
+    other => panic!("no feature for SDL version {other:?}"),
+  })
+}
+
+#[test]
+fn newer_functions_are_feature_gated() {
+  let since = header_since_versions();
+  assert!(since.len() > 500, "header parse failed");
+  let src_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+  let mut problems = Vec::new();
+  for entry in fs::read_dir(src_dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().map_or(true, |e| e != "rs") {
+      continue;
+    }
+    let text = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+      let rest = match line.trim_start().strip_prefix("pub fn SDL_") {
+        Some(rest) => rest,
+        None => continue,
+      };
+      let name = format!("SDL_{}", rest.split('(').next().unwrap());
+      let expected = since.get(&name).copied().and_then(feature_for);
+      // the attributes go between the doc comment and the `pub fn`
+      let found = lines[..i]
+        .iter()
+        .rev()
+        .take_while(|l| l.trim_start().starts_with("#["))
+        .find_map(|l| {
+          l.trim().strip_prefix("#[cfg(feature = \"")?.strip_suffix("\")]")
+        });
+      if found != expected {
+        problems.push(format!(
+          "{}: {name} is gated on {found:?}, expected {expected:?}",
+          path.file_name().unwrap().to_string_lossy()
+        ));
+      }
+    }
+  }
+  assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
+
+#[test]
+fn gated_functions_have_optional_lookups() {
+  let src_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+  let optional = fs::read_to_string(src_dir.join("optional.rs")).unwrap();
+  let mut problems = Vec::new();
+  for entry in fs::read_dir(&src_dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().map_or(true, |e| e != "rs") {
+      continue;
+    }
+    let module = path.file_stem().unwrap().to_string_lossy().into_owned();
+    let text = fs::read_to_string(&path).unwrap();
+    let mut gate: Option<&str> = None;
+    for line in text.lines().map(str::trim_start) {
+      if let Some(feature) = line
+        .strip_prefix("#[cfg(feature = \"")
+        .and_then(|l| l.strip_suffix("\")]"))
+        .filter(|f| f.starts_with("sdl_"))
+      {
+        gate = Some(feature);
+      } else if let Some(rest) = line.strip_prefix("pub fn SDL_") {
+        if let Some(feature) = gate {
+          let name = format!("SDL_{}", rest.split('(').next().unwrap());
+          let entry = format!("\"{feature}\" {module}::{name}(");
+          if !optional.contains(&entry) {
+            problems.push(format!("missing `{entry}..` in optional.rs"));
+          }
+        }
+        gate = None;
+      } else if !line.starts_with("#[") {
+        gate = None;
+      }
+    }
+  }
+  assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
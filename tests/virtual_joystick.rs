@@ -0,0 +1,43 @@
+#![cfg(all(
+  feature = "alloc",
+  feature = "sdl_2_24",
+  not(feature = "dynamic_loading")
+))]
+
+use fermium::prelude::*;
+use std::sync::{
+  atomic::{AtomicU32, Ordering},
+  Arc,
+};
+
+#[test]
+fn virtual_joystick_rumble_reaches_the_callback() {
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_JOYSTICK), 0);
+  }
+  let rumbles = Arc::new(AtomicU32::new(0));
+  let rumbles_in_callback = rumbles.clone();
+  let virtual_joystick =
+    VirtualJoystickBuilder::new(SDL_JOYSTICK_TYPE_GAMECONTROLLER)
+      .name(core::ffi::CStr::from_bytes_with_nul(b"fermium test\0").unwrap())
+      .axes(2)
+      .buttons(4)
+      .on_rumble(move |lo, hi| {
+        rumbles_in_callback
+          .store(u32::from(lo) << 16 | u32::from(hi), Ordering::SeqCst);
+        0
+      })
+      .attach()
+      .expect("couldn't attach a virtual joystick");
+  let device_index = virtual_joystick.device_index().unwrap();
+  unsafe {
+    assert_eq!(SDL_JoystickIsVirtual(device_index), SDL_TRUE);
+    let joystick = SDL_JoystickOpen(device_index);
+    assert!(!joystick.is_null());
+    assert_eq!(SDL_JoystickRumble(joystick, 0x1234, 0x5678, 100), 0);
+    SDL_JoystickClose(joystick);
+  }
+  assert_eq!(rumbles.load(Ordering::SeqCst), 0x1234_5678);
+  drop(virtual_joystick);
+  unsafe { SDL_Quit() };
+}